@@ -0,0 +1,38 @@
+use std::hint::black_box;
+
+use check_keyword::{CheckKeyword, Keyword};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const KEYWORDS: [&str; 6] = ["as", "match", "while", "macro_rules", "gen", "_"];
+const NON_KEYWORDS: [&str; 6] = ["a", "hello", "matches", "identifier", "whale", "a_very_long_field_name"];
+
+/// The linear scan that lookups used to do.
+fn linear(name: &str) -> bool {
+    Keyword::ALL.iter().any(|keyword| keyword.as_str() == name)
+}
+
+fn bench(c: &mut Criterion, group: &str, names: &[&str]) {
+    let mut group = c.benchmark_group(group);
+    group.bench_function("linear", |b| b.iter(|| {
+        for name in names {
+            black_box(linear(black_box(name)));
+        }
+    }));
+    group.bench_function("is_keyword", |b| b.iter(|| {
+        for name in names {
+            black_box(black_box(name).is_keyword());
+        }
+    }));
+    group.finish();
+}
+
+fn keywords(c: &mut Criterion) {
+    bench(c, "keywords", &KEYWORDS);
+}
+
+fn non_keywords(c: &mut Criterion) {
+    bench(c, "non_keywords", &NON_KEYWORDS);
+}
+
+criterion_group!(benches, keywords, non_keywords);
+criterion_main!(benches);
@@ -0,0 +1,295 @@
+//! C and C++ keywords for each standard, since both languages keep adding them, like `bool`
+//! becoming a keyword in C23.
+//!
+//! Besides keywords, some identifiers are reserved for the implementation: in C, those starting
+//! with `__` or `_` and an uppercase letter, and in C++, also any containing `__`. Keywords get a
+//! trailing underscore, and reserved identifiers are prefixed with `x` (and in C++, get an `x`
+//! between any double underscores).
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::c::{C, Cpp};
+//!
+//! assert_eq!("register".into_safe_in_set(&C::C11), "register_");
+//! assert_eq!("_Bool".into_safe_in_set(&C::C89), "x_Bool");
+//! assert_eq!("class".into_safe_in_set(&Cpp::CPP23), "class_");
+//! assert_eq!("co_await".into_safe_in_set(&Cpp::CPP17), "co_await");
+//! ```
+
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A C standard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CStandard {
+    C89,
+    C99,
+    C11,
+    C17,
+    C23,
+}
+
+/// A C++ standard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CppStandard {
+    Cpp98,
+    Cpp03,
+    Cpp11,
+    Cpp14,
+    Cpp17,
+    Cpp20,
+    Cpp23,
+}
+
+use CStandard::*;
+use CppStandard::*;
+
+/// Each C keyword, and the standard it was added in.
+pub const C_KEYWORDS: &[(&str, CStandard)] = &[
+    ("auto", C89), ("break", C89), ("case", C89), ("char", C89), ("const", C89),
+    ("continue", C89), ("default", C89), ("do", C89), ("double", C89), ("else", C89),
+    ("enum", C89), ("extern", C89), ("float", C89), ("for", C89), ("goto", C89), ("if", C89),
+    ("int", C89), ("long", C89), ("register", C89), ("return", C89), ("short", C89),
+    ("signed", C89), ("sizeof", C89), ("static", C89), ("struct", C89), ("switch", C89),
+    ("typedef", C89), ("union", C89), ("unsigned", C89), ("void", C89), ("volatile", C89),
+    ("while", C89),
+
+    ("inline", C99), ("restrict", C99), ("_Bool", C99), ("_Complex", C99), ("_Imaginary", C99),
+
+    ("_Alignas", C11), ("_Alignof", C11), ("_Atomic", C11), ("_Generic", C11),
+    ("_Noreturn", C11), ("_Static_assert", C11), ("_Thread_local", C11),
+
+    ("alignas", C23), ("alignof", C23), ("bool", C23), ("constexpr", C23), ("false", C23),
+    ("nullptr", C23), ("static_assert", C23), ("thread_local", C23), ("true", C23),
+    ("typeof", C23), ("typeof_unqual", C23), ("_BitInt", C23), ("_Decimal32", C23),
+    ("_Decimal64", C23), ("_Decimal128", C23),
+];
+
+/// Each C++ keyword, including alternative tokens like `and`, and the standard it was added in.
+pub const CPP_KEYWORDS: &[(&str, CppStandard)] = &[
+    ("and", Cpp98), ("and_eq", Cpp98), ("asm", Cpp98), ("auto", Cpp98), ("bitand", Cpp98),
+    ("bitor", Cpp98), ("bool", Cpp98), ("break", Cpp98), ("case", Cpp98), ("catch", Cpp98),
+    ("char", Cpp98), ("class", Cpp98), ("compl", Cpp98), ("const", Cpp98),
+    ("const_cast", Cpp98), ("continue", Cpp98), ("default", Cpp98), ("delete", Cpp98),
+    ("do", Cpp98), ("double", Cpp98), ("dynamic_cast", Cpp98), ("else", Cpp98), ("enum", Cpp98),
+    ("explicit", Cpp98), ("export", Cpp98), ("extern", Cpp98), ("false", Cpp98),
+    ("float", Cpp98), ("for", Cpp98), ("friend", Cpp98), ("goto", Cpp98), ("if", Cpp98),
+    ("inline", Cpp98), ("int", Cpp98), ("long", Cpp98), ("mutable", Cpp98),
+    ("namespace", Cpp98), ("new", Cpp98), ("not", Cpp98), ("not_eq", Cpp98),
+    ("operator", Cpp98), ("or", Cpp98), ("or_eq", Cpp98), ("private", Cpp98),
+    ("protected", Cpp98), ("public", Cpp98), ("register", Cpp98),
+    ("reinterpret_cast", Cpp98), ("return", Cpp98), ("short", Cpp98), ("signed", Cpp98),
+    ("sizeof", Cpp98), ("static", Cpp98), ("static_cast", Cpp98), ("struct", Cpp98),
+    ("switch", Cpp98), ("template", Cpp98), ("this", Cpp98), ("throw", Cpp98), ("true", Cpp98),
+    ("try", Cpp98), ("typedef", Cpp98), ("typeid", Cpp98), ("typename", Cpp98),
+    ("union", Cpp98), ("unsigned", Cpp98), ("using", Cpp98), ("virtual", Cpp98),
+    ("void", Cpp98), ("volatile", Cpp98), ("wchar_t", Cpp98), ("while", Cpp98), ("xor", Cpp98),
+    ("xor_eq", Cpp98),
+
+    ("alignas", Cpp11), ("alignof", Cpp11), ("char16_t", Cpp11), ("char32_t", Cpp11),
+    ("constexpr", Cpp11), ("decltype", Cpp11), ("noexcept", Cpp11), ("nullptr", Cpp11),
+    ("static_assert", Cpp11), ("thread_local", Cpp11),
+
+    ("char8_t", Cpp20), ("co_await", Cpp20), ("co_return", Cpp20), ("co_yield", Cpp20),
+    ("concept", Cpp20), ("consteval", Cpp20), ("constinit", Cpp20), ("requires", Cpp20),
+];
+
+/// Checks if `name` is a keyword in the given C standard.
+pub fn is_c_keyword(name: &str, standard: CStandard) -> bool {
+    C_KEYWORDS.iter().any(|&(keyword, since)| keyword == name && since <= standard)
+}
+
+/// Checks if `name` is a keyword in the given C++ standard.
+pub fn is_cpp_keyword(name: &str, standard: CppStandard) -> bool {
+    CPP_KEYWORDS.iter().any(|&(keyword, since)| keyword == name && since <= standard)
+}
+
+/// Checks if `name` starts with `__`, or `_` and an uppercase letter, which C reserves in every scope.
+pub fn is_c_reserved_identifier(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() >= 2 && bytes[0] == b'_' && (bytes[1] == b'_' || bytes[1].is_ascii_uppercase())
+}
+
+/// Checks if `name` is reserved like in C, or contains `__` anywhere, which C++ also reserves.
+pub fn is_cpp_reserved_identifier(name: &str) -> bool {
+    is_c_reserved_identifier(name) || name.contains("__")
+}
+
+/// A [KeywordSet] of C keywords and reserved identifiers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct C {
+    pub standard: CStandard,
+}
+
+impl C {
+    pub const C89: C = C { standard: C89 };
+    pub const C99: C = C { standard: C99 };
+    pub const C11: C = C { standard: C11 };
+    pub const C17: C = C { standard: C17 };
+    pub const C23: C = C { standard: C23 };
+}
+
+impl KeywordSet for C {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_c_keyword(name, self.standard) || is_c_reserved_identifier(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Custom(&escape_c)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        unreserve(name, is_c_reserved_identifier)
+    }
+}
+
+/// A [KeywordSet] of C++ keywords and reserved identifiers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cpp {
+    pub standard: CppStandard,
+}
+
+impl Cpp {
+    pub const CPP98: Cpp = Cpp { standard: Cpp98 };
+    pub const CPP03: Cpp = Cpp { standard: Cpp03 };
+    pub const CPP11: Cpp = Cpp { standard: Cpp11 };
+    pub const CPP14: Cpp = Cpp { standard: Cpp14 };
+    pub const CPP17: Cpp = Cpp { standard: Cpp17 };
+    pub const CPP20: Cpp = Cpp { standard: Cpp20 };
+    pub const CPP23: Cpp = Cpp { standard: Cpp23 };
+}
+
+impl KeywordSet for Cpp {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_cpp_keyword(name, self.standard) || is_cpp_reserved_identifier(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Custom(&escape_cpp)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        unreserve(name, is_cpp_reserved_identifier)
+    }
+}
+
+/// Collapses repeated underscores in a reserved identifier, and adds `x` to the beginning if it
+/// still starts with `_` and an uppercase letter.
+#[cfg(feature = "alloc")]
+fn unreserve(name: &str, is_reserved_identifier: fn(&str) -> bool) -> Cow<'_, str> {
+    if !is_reserved_identifier(name) {
+        return Cow::Borrowed(name);
+    }
+    let mut unreserved = String::with_capacity(name.len() + 1);
+    for c in name.chars() {
+        if c != '_' || !unreserved.ends_with('_') {
+            unreserved.push(c);
+        }
+    }
+    if is_reserved_identifier(&unreserved) {
+        unreserved.insert(0, 'x');
+    }
+    Cow::Owned(unreserved)
+}
+
+/// Prefixes reserved identifiers with `x`, and adds `_` to the end of anything else.
+#[cfg(feature = "alloc")]
+fn escape_c(name: &str) -> String {
+    if is_c_reserved_identifier(name) {
+        String::from("x") + name
+    } else {
+        String::from(name) + "_"
+    }
+}
+
+/// Like [escape_c], but also separates double underscores with `x`.
+#[cfg(feature = "alloc")]
+fn escape_cpp(name: &str) -> String {
+    if !is_cpp_reserved_identifier(name) {
+        return String::from(name) + "_";
+    }
+    let mut escaped = String::with_capacity(name.len() + 2);
+    if name.starts_with('_') {
+        escaped.push('x');
+    }
+    for c in name.chars() {
+        if c == '_' && escaped.ends_with('_') {
+            escaped.push('x');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_c_keyword("register", C89));
+        assert!(!is_c_keyword("inline", C89));
+        assert!(is_c_keyword("inline", C99));
+        assert!(!is_c_keyword("bool", C17));
+        assert!(is_c_keyword("bool", C23));
+
+        assert!(is_cpp_keyword("and", Cpp98));
+        assert!(!is_cpp_keyword("nullptr", Cpp03));
+        assert!(is_cpp_keyword("nullptr", Cpp11));
+        assert!(!is_cpp_keyword("co_await", Cpp17));
+        assert!(is_cpp_keyword("concept", Cpp23));
+    }
+
+    #[test]
+    fn reserved_identifiers() {
+        assert!(is_c_reserved_identifier("__init"));
+        assert!(is_c_reserved_identifier("_Foo"));
+        assert!(!is_c_reserved_identifier("_foo"));
+        assert!(!is_c_reserved_identifier("a__b"));
+        assert!(!is_c_reserved_identifier("_"));
+        assert!(is_cpp_reserved_identifier("a__b"));
+    }
+
+    #[test]
+    fn is_keyword_in_set() {
+        assert!("template".is_keyword_in_set(&Cpp::CPP98));
+        assert!(!"template".is_keyword_in_set(&C::C23));
+        assert!("_Atomic".is_keyword_in_set(&C::C89));
+        assert!("new".is_keyword_in_set(&Cpp::CPP11));
+        assert!(!"new".is_keyword_in_set(&C::C11));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("int".into_safe_in_set(&C::C89), "int_");
+        assert_eq!(String::from("__init").into_safe_in_set(&C::C89), "x__init");
+        assert_eq!("a__b".into_safe_in_set(&C::C89), "a__b");
+
+        assert_eq!("new".into_safe_in_set(&Cpp::CPP98), "new_");
+        assert_eq!("a__b".into_safe_in_set(&Cpp::CPP98), "a_x_b");
+        assert_eq!("__init".into_safe_in_set(&Cpp::CPP98), "x_x_init");
+        assert_eq!("_Foo".into_safe_in_set(&Cpp::CPP98), "x_Foo");
+        assert_eq!("a___b".into_safe_in_set(&Cpp::CPP98), "a_x_x_b");
+        assert_eq!("hello".into_safe_in_set(&Cpp::CPP23), "hello");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all() {
+        assert_eq!("__init".into_safe_in_all(&[&C::C11]).unwrap(), "_init");
+        assert_eq!("_Foo".into_safe_in_all(&[&C::C11]).unwrap(), "x_Foo");
+        assert_eq!("__init".into_safe_in_all(&[&Cpp::CPP20]).unwrap(), "_init");
+        assert_eq!("a__b".into_safe_in_all(&[&Cpp::CPP20]).unwrap(), "a_b");
+        assert_eq!("__".into_safe_in_all(&[&Cpp::CPP20, &RustKeywords::default()]).unwrap(), "_2");
+
+        let targets = [&Cpp::CPP20 as &dyn KeywordSet, &["new_"]];
+        assert_eq!("new".into_safe_in_all(&targets).unwrap(), "new_2");
+    }
+}
@@ -0,0 +1,92 @@
+//! C# keywords, escaped with the verbatim `@` prefix, and the contextual keywords like `record`
+//! that only need it in some places.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::csharp::CSharp;
+//!
+//! assert_eq!("class".into_safe_in_set(&CSharp::KEYWORDS), "@class");
+//! assert_eq!("record".into_safe_in_set(&CSharp::KEYWORDS), "record");
+//! assert_eq!("record".into_safe_in_set(&CSharp::CONTEXTUAL), "@record");
+//! ```
+
+use super::*;
+
+/// C#'s reserved keywords.
+pub const KEYWORDS: &[&str] = &[
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked", "class",
+    "const", "continue", "decimal", "default", "delegate", "do", "double", "else", "enum", "event",
+    "explicit", "extern", "false", "finally", "fixed", "float", "for", "foreach", "goto", "if",
+    "implicit", "in", "int", "interface", "internal", "is", "lock", "long", "namespace", "new",
+    "null", "object", "operator", "out", "override", "params", "private", "protected", "public",
+    "readonly", "ref", "return", "sbyte", "sealed", "short", "sizeof", "stackalloc", "static",
+    "string", "struct", "switch", "this", "throw", "true", "try", "typeof", "uint", "ulong",
+    "unchecked", "unsafe", "ushort", "using", "virtual", "void", "volatile", "while",
+];
+
+/// C#'s contextual keywords, which are only keywords in certain places, like `var` and `async`.
+pub const CONTEXTUAL_KEYWORDS: &[&str] = &[
+    "add", "alias", "and", "args", "ascending", "async", "await", "by", "descending", "dynamic",
+    "equals", "file", "from", "get", "global", "group", "init", "into", "join", "let", "managed",
+    "nameof", "nint", "not", "notnull", "nuint", "on", "or", "orderby", "partial", "record",
+    "remove", "required", "scoped", "select", "set", "unmanaged", "value", "var", "when", "where",
+    "with", "yield",
+];
+
+/// Checks if `name` is a reserved keyword.
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` is a contextual keyword.
+pub fn is_contextual_keyword(name: &str) -> bool {
+    CONTEXTUAL_KEYWORDS.contains(&name)
+}
+
+/// A [KeywordSet] of C# keywords, escaped with a verbatim `@` prefix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct CSharp {
+    /// Also escape contextual keywords.
+    pub contextual: bool,
+}
+
+impl CSharp {
+    /// Only reserved keywords.
+    pub const KEYWORDS: CSharp = CSharp { contextual: false };
+    /// Reserved and contextual keywords.
+    pub const CONTEXTUAL: CSharp = CSharp { contextual: true };
+}
+
+impl KeywordSet for CSharp {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_keyword(name) || (self.contextual && is_contextual_keyword(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Affix { prefix: "@", suffix: "" }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_keyword("namespace"));
+        assert!(!is_keyword("var"));
+        assert!(is_contextual_keyword("var"));
+        assert!(!is_contextual_keyword("class"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("event".into_safe_in_set(&CSharp::KEYWORDS), "@event");
+        assert_eq!(String::from("string").into_safe_in_set(&CSharp::KEYWORDS), "@string");
+        assert_eq!("async".into_safe_in_set(&CSharp::KEYWORDS), "async");
+        assert_eq!("async".into_safe_in_set(&CSharp::CONTEXTUAL), "@async");
+        assert_eq!("name".into_safe_in_set(&CSharp::CONTEXTUAL), "name");
+    }
+}
@@ -0,0 +1,106 @@
+use super::*;
+
+use core::fmt;
+
+/// Writes a name with the same escape as [CheckKeyword::into_safe], without allocating.
+///
+/// Returned by [CheckKeyword::safe_display].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SafeDisplay<'a> {
+    name: &'a str,
+    escape: Escape,
+}
+
+impl<'a> SafeDisplay<'a> {
+    pub(crate) fn new(name: &'a str, escape: Escape) -> Self {
+        SafeDisplay { name, escape }
+    }
+
+    /// The escape that will be applied.
+    pub fn escape(&self) -> Escape {
+        self.escape
+    }
+
+    /// Checks if this would write exactly `original`, which is the string it was made from.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn is_same_as(&self, original: &str) -> bool {
+        match self.escape {
+            Escape::Unchanged => original.len() == self.name.len(),
+            Escape::Raw => original.len() == self.name.len() + 2,
+            Escape::TrailingUnderscore => false,
+        }
+    }
+
+    /// Writes into the start of `buffer`, and returns the written part, or `None` if it didn't fit.
+    pub(crate) fn write_to(self, buffer: &mut [u8]) -> Option<&str> {
+        let mut writer = SliceWriter { buffer, len: 0 };
+        fmt::write(&mut writer, format_args!("{}", self)).ok()?;
+        let SliceWriter { buffer, len } = writer;
+        core::str::from_utf8(&buffer[..len]).ok()
+    }
+}
+
+/// A [fmt::Write] into a fixed buffer, that fails if the buffer is full.
+struct SliceWriter<'b> {
+    buffer: &'b mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buffer.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl fmt::Display for SafeDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.escape {
+            Escape::Unchanged => f.write_str(self.name),
+            Escape::Raw => write!(f, "r#{}", self.name),
+            Escape::TrailingUnderscore => write!(f, "{}_", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fmt::Write;
+
+    #[test]
+    fn write_safe() {
+        let mut buffer = [0; 8];
+        assert_eq!("match".write_safe(&mut buffer), Some("r#match"));
+        assert_eq!("self".write_safe(&mut buffer), Some("self_"));
+        assert_eq!("gen".write_safe_for(&mut buffer, Edition::Edition2024), Some("r#gen"));
+        assert_eq!("continue".write_safe(&mut buffer), None);
+        assert_eq!("".write_safe(&mut []), Some(""));
+    }
+
+    #[test]
+    fn safe_display() {
+        let mut buffer = String::new();
+        write!(buffer, "struct {} {{ {}: u8 }}", "match".safe_display(), "hello".safe_display()).unwrap();
+        assert_eq!(buffer, "struct r#match { hello: u8 }");
+
+        assert_eq!(String::from("self").safe_display().to_string(), "self_");
+        assert_eq!("gen".safe_display_in(Edition::Edition2024).to_string(), "r#gen");
+        assert_eq!("gen".safe_display_in(Edition::Edition2024).escape(), Escape::Raw);
+
+        let mut bytes = Vec::new();
+        std::io::Write::write_fmt(&mut bytes, format_args!("{}", "type".safe_display())).unwrap();
+        assert_eq!(bytes, b"r#type");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn matches_into_safe() {
+        for word in ["hello", "match", "self", "_", "async", "gen", "union", "r#match", "r#foo", "r#self"] {
+            assert_eq!(word.safe_display().to_string(), word.into_safe());
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// What the empty string is encoded as, since it can't be built from the escapes.
+const EMPTY: &str = "_empty";
+
+/// The error returned by [CheckKeyword::decode_ident] if the name wasn't made by
+/// [CheckKeyword::encode_ident].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeIdentError;
+
+impl fmt::Display for DecodeIdentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not an encoded identifier")
+    }
+}
+
+impl core::error::Error for DecodeIdentError {}
+
+/// Checks if `c` can be kept as-is at position `index`.
+fn is_plain(index: usize, c: char) -> bool {
+    c.is_ascii_alphabetic() || (index > 0 && c.is_ascii_digit())
+}
+
+/// Encodes `name` as an identifier that isn't a keyword in any edition.
+///
+/// ASCII letters and digits are kept, `_` becomes `__`, and any other character becomes
+/// `_`, its code point in lowercase hex, and `_`. A leading digit and the first letter of a
+/// keyword are escaped the same way.
+pub(crate) fn encode(name: &str) -> Cow<'_, str> {
+    if name.is_empty() {
+        return Cow::Borrowed(EMPTY);
+    }
+    let keyword = is_keyword_in(name, Edition::Edition2024);
+    if !keyword && name.chars().enumerate().all(|(i, c)| is_plain(i, c)) {
+        return Cow::Borrowed(name);
+    }
+    let mut encoded = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c == '_' {
+            encoded.push_str("__");
+        } else if is_plain(i, c) && !(keyword && i == 0) {
+            encoded.push(c);
+        } else {
+            write!(encoded, "_{:x}_", c as u32).unwrap();
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+/// Decodes a name made by [encode].
+///
+/// Every string has exactly one encoding, so anything that doesn't encode back to `name` is rejected.
+pub(crate) fn decode(name: &str) -> Result<Cow<'_, str>, DecodeIdentError> {
+    if name == EMPTY {
+        return Ok(Cow::Borrowed(""));
+    }
+    let mut decoded = String::with_capacity(name.len());
+    let mut rest = name;
+    while let Some(start) = rest.find('_') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let end = rest.find('_').ok_or(DecodeIdentError)?;
+        if end == 0 {
+            decoded.push('_');
+        } else {
+            let code = u32::from_str_radix(&rest[..end], 16).map_err(|_| DecodeIdentError)?;
+            decoded.push(char::from_u32(code).ok_or(DecodeIdentError)?);
+        }
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+
+    match encode(&decoded) {
+        encoded if encoded != name => Err(DecodeIdentError),
+        _ if decoded == name => Ok(Cow::Borrowed(name)),
+        _ => Ok(Cow::Owned(decoded))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_ident() {
+        assert_eq!("hello".encode_ident(), "hello");
+        assert_eq!("a-b".encode_ident(), "a_2d_b");
+        assert_eq!("a.b".encode_ident(), "a_2e_b");
+        assert_eq!("a_b".encode_ident(), "a__b");
+        assert_eq!("_".encode_ident(), "__");
+        assert_eq!("2fast".encode_ident(), "_32_fast");
+        assert_eq!("match".encode_ident(), "_6d_atch");
+        assert_eq!("gen".encode_ident(), "_67_en");
+        assert_eq!("self".encode_ident(), "_73_elf");
+        assert_eq!("r#match".encode_ident(), "r_23_match");
+        assert_eq!("naïve".encode_ident(), "na_ef_ve");
+        assert_eq!("".encode_ident(), "_empty");
+        assert_eq!(String::from("a b").encode_ident(), "a_20_b");
+    }
+
+    #[test]
+    fn decode_ident() {
+        for name in ["hello", "a-b", "a_b", "_", "__", "2fast", "match", "self_", "naïve", "", "🦀 crab", "_empty"] {
+            assert_eq!(name.encode_ident().decode_ident().as_deref(), Ok(name));
+            assert!(name.encode_ident().is_valid_ident_in(Edition::Edition2024), "{}", name);
+        }
+        assert_eq!(String::from("a_2d_b").decode_ident(), Ok(String::from("a-b")));
+    }
+
+    #[test]
+    fn decode_invalid() {
+        for name in ["_", "a_", "a_2d", "_zz_", "_61_", "_0061_", "_2D_", "_+2d_", "_110000_", "match", "2fast", "naïve"] {
+            assert_eq!(name.decode_ident(), Err(DecodeIdentError), "{}", name);
+        }
+    }
+}
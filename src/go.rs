@@ -0,0 +1,89 @@
+//! Go's 25 keywords, and its predeclared identifiers like `len` and `string`, which aren't
+//! reserved but are confusing to shadow.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::go::Go;
+//!
+//! assert_eq!("func".into_safe_in_set(&Go::KEYWORDS), "func_");
+//! assert_eq!("len".into_safe_in_set(&Go::KEYWORDS), "len");
+//! assert_eq!("len".into_safe_in_set(&Go::PREDECLARED), "len_");
+//! ```
+
+use super::*;
+
+/// Go's keywords.
+pub const KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough", "for",
+    "func", "go", "goto", "if", "import", "interface", "map", "package", "range", "return", "select",
+    "struct", "switch", "type", "var",
+];
+
+/// Go's predeclared types, constants, and functions, which can be used as names but shadow the
+/// predeclared one.
+pub const PREDECLARED: &[&str] = &[
+    "any", "append", "bool", "byte", "cap", "clear", "close", "comparable", "complex", "complex128",
+    "complex64", "copy", "delete", "error", "false", "float32", "float64", "imag", "int", "int16",
+    "int32", "int64", "int8", "iota", "len", "make", "max", "min", "new", "nil", "panic", "print",
+    "println", "real", "recover", "rune", "string", "true", "uint", "uint16", "uint32", "uint64",
+    "uint8", "uintptr",
+];
+
+/// Checks if `name` is a keyword.
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` would shadow a predeclared identifier.
+pub fn is_predeclared(name: &str) -> bool {
+    PREDECLARED.contains(&name)
+}
+
+/// A [KeywordSet] of Go names, escaped with a trailing underscore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Go {
+    /// Also escape names that would shadow a predeclared identifier.
+    pub predeclared: bool,
+}
+
+impl Go {
+    /// Only keywords.
+    pub const KEYWORDS: Go = Go { predeclared: false };
+    /// Keywords and predeclared identifiers.
+    pub const PREDECLARED: Go = Go { predeclared: true };
+}
+
+impl KeywordSet for Go {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_keyword(name) || (self.predeclared && is_predeclared(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::TrailingUnderscore
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_keyword("chan"));
+        assert!(!is_keyword("nil"));
+        assert!(is_predeclared("nil"));
+        assert!(is_predeclared("error"));
+        assert!(!is_predeclared("type"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("type".into_safe_in_set(&Go::KEYWORDS), "type_");
+        assert_eq!(String::from("range").into_safe_in_set(&Go::KEYWORDS), "range_");
+        assert_eq!("error".into_safe_in_set(&Go::KEYWORDS), "error");
+        assert_eq!("error".into_safe_in_set(&Go::PREDECLARED), "error_");
+        assert_eq!("value".into_safe_in_set(&Go::PREDECLARED), "value");
+    }
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+use core::fmt;
+
+/// The reason a string can't be made into a valid identifier by [CheckKeyword::try_into_safe].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdentError {
+    /// The string is empty.
+    Empty,
+    /// The first character can't start an identifier.
+    InvalidStart(char),
+    /// A character after the first can't be part of an identifier.
+    InvalidContinue(char),
+    /// The string is a keyword that can't be used as a raw identifier, like `self`.
+    NonRawKeyword,
+    /// The string is `_`, which is not an identifier.
+    LoneUnderscore,
+}
+
+impl fmt::Display for IdentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentError::Empty => write!(f, "identifier is empty"),
+            IdentError::InvalidStart(c) => write!(f, "identifier can't start with {:?}", c),
+            IdentError::InvalidContinue(c) => write!(f, "identifier can't contain {:?}", c),
+            IdentError::NonRawKeyword => write!(f, "keyword can't be a raw identifier"),
+            IdentError::LoneUnderscore => write!(f, "`_` is not an identifier"),
+        }
+    }
+}
+
+impl core::error::Error for IdentError {}
+
+/// Checks that `name` is an identifier or keyword, following the rules in the reference:
+/// <https://doc.rust-lang.org/reference/identifiers.html>
+pub(crate) fn check_ident_or_keyword(name: &str) -> Result<(), IdentError> {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => return Err(IdentError::Empty),
+        Some(c) if c != '_' && !unicode_ident::is_xid_start(c) => {
+            return Err(IdentError::InvalidStart(c))
+        }
+        _ => {}
+    }
+    match chars.find(|&c| !unicode_ident::is_xid_continue(c)) {
+        Some(c) => Err(IdentError::InvalidContinue(c)),
+        None => Ok(())
+    }
+}
+
+/// Checks if `name` is a raw identifier.
+pub(crate) fn is_valid_raw_ident(name: &str) -> bool {
+    match name.strip_prefix("r#") {
+        Some(rest) => check_ident_or_keyword(rest).is_ok() && !NON_RAW_KEYWORDS.contains(&rest),
+        None => false
+    }
+}
+
+/// Checks if `name` is a raw identifier, or an identifier that isn't a keyword in `edition`.
+pub(crate) fn is_valid_ident_in(name: &str, edition: Edition) -> bool {
+    is_valid_raw_ident(name) || (check_ident_or_keyword(name).is_ok() && !is_keyword_in(name, edition))
+}
+
+/// Checks that `name` can be made into an identifier with a lossless escape.
+///
+/// Names like `self_` are already identifiers, so they don't get the extra underscore that
+/// [CheckKeyword::into_safe] adds for [CheckKeyword::original_name]. Only one "r#" is removed,
+/// so names like `r#r#match` are rejected rather than fixed.
+#[cfg(feature = "alloc")]
+pub(crate) fn check_escapable(name: &str, edition: Edition) -> Result<SafeDisplay<'_>, IdentError> {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    check_ident_or_keyword(name)?;
+    match name {
+        "_" => Err(IdentError::LoneUnderscore),
+        name if NON_RAW_KEYWORDS.contains(&name) => Err(IdentError::NonRawKeyword),
+        name if is_non_raw_keyword_with_underscores(name) => Ok(SafeDisplay::new(name, Escape::Unchanged)),
+        name => Ok(safe_display_in(name, edition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_into_safe() {
+        assert_eq!("match".try_into_safe(), Ok(String::from("r#match")));
+        assert_eq!(String::from("naïve").try_into_safe(), Ok(String::from("naïve")));
+        assert_eq!("_foo".try_into_safe(), Ok(String::from("_foo")));
+
+        assert_eq!("".try_into_safe(), Err(IdentError::Empty));
+        assert_eq!("1abc".try_into_safe(), Err(IdentError::InvalidStart('1')));
+        assert_eq!("foo-bar".try_into_safe(), Err(IdentError::InvalidContinue('-')));
+        assert_eq!("r#match".try_into_safe(), Ok(String::from("r#match")));
+        assert_eq!("r#foo".try_into_safe(), Ok(String::from("foo")));
+        assert_eq!("r#self".try_into_safe(), Err(IdentError::NonRawKeyword));
+        assert_eq!("r#".try_into_safe(), Err(IdentError::Empty));
+        assert_eq!("r#a#b".try_into_safe(), Err(IdentError::InvalidContinue('#')));
+        assert_eq!("r#r#match".try_into_safe(), Err(IdentError::InvalidContinue('#')));
+        assert_eq!(String::from("self").try_into_safe(), Err(IdentError::NonRawKeyword));
+        assert_eq!("_".try_into_safe(), Err(IdentError::LoneUnderscore));
+        assert_eq!("self_".try_into_safe(), Ok(String::from("self_")));
+        assert_eq!("crate__".try_into_safe(), Ok(String::from("crate__")));
+        assert_eq!("__".try_into_safe(), Ok(String::from("__")));
+        assert_eq!("r#Self_".try_into_safe(), Ok(String::from("Self_")));
+    }
+
+    #[test]
+    fn is_valid_ident() {
+        assert!("foo".is_valid_ident());
+        assert!("_foo".is_valid_ident());
+        assert!("__".is_valid_ident());
+        assert!(String::from("naïve").is_valid_ident());
+        assert!("r#match".is_valid_ident());
+        assert!("r#foo".is_valid_ident());
+
+        assert!(!"".is_valid_ident());
+        assert!(!"_".is_valid_ident());
+        assert!(!"match".is_valid_ident());
+        assert!(!"2fast".is_valid_ident());
+        assert!(!"my-field".is_valid_ident());
+        assert!(!"r#self".is_valid_ident());
+        assert!(!"r#".is_valid_ident());
+
+        assert!("gen".is_valid_ident_in(Edition::Edition2021));
+        assert!(!"gen".is_valid_ident_in(Edition::Edition2024));
+    }
+
+    #[test]
+    fn is_valid_raw_ident() {
+        assert!("r#match".is_valid_raw_ident());
+        assert!(String::from("r#foo").is_valid_raw_ident());
+
+        assert!(!"match".is_valid_raw_ident());
+        assert!(!"r#self".is_valid_raw_ident());
+        assert!(!"r#_".is_valid_raw_ident());
+        assert!(!"r#1".is_valid_raw_ident());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn original_name_doesnt_undo_try_into_safe() {
+        assert_eq!("self_".try_into_safe().unwrap().original_name(), "self");
+        assert_eq!("__".try_into_safe().unwrap().original_name(), "_");
+        assert_eq!("self_".into_safe().original_name(), "self_");
+        assert_eq!("match".try_into_safe().unwrap().original_name(), "match");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_into_safe_for() {
+        assert_eq!("gen".try_into_safe_for(Edition::Edition2021), Ok(String::from("gen")));
+        assert_eq!("gen".try_into_safe_for(Edition::Edition2024), Ok(String::from("r#gen")));
+    }
+}
@@ -1,35 +1,70 @@
 use super::*;
 
+#[cfg(feature = "alloc")]
 impl<T: AsRef<str> + From<String>> CheckKeyword<T> for T {
-    fn is_keyword(&self) -> bool {
-        KEYWORDS.contains(&self.as_ref())
+    fn into_safe_for(self, edition: Edition) -> Self {
+        let safe = self.safe_display_in(edition);
+        if safe.is_same_as(self.as_ref()) {
+            self
+        } else {
+            safe.to_string().into()
+        }
     }
 
-    fn into_safe(self) -> Self {
-        if self.is_keyword() {
-            let safe = format!("r#{}", self.as_ref());
-            safe.into()
-        } else {
-            self
+    fn into_safe_with_for(self, strategy: EscapeStrategy<'_>, edition: Edition) -> Self {
+        match strategy::into_safe_with(self.as_ref(), strategy, edition) {
+            Cow::Borrowed(safe) if safe.len() == self.as_ref().len() => self,
+            safe => safe.into_owned().into()
+        }
+    }
+
+    fn into_safe_in_set<S: KeywordSet + ?Sized>(self, set: &S) -> Self {
+        match set::into_safe_in_set(self.as_ref(), set) {
+            Cow::Borrowed(safe) if safe.len() == self.as_ref().len() => self,
+            safe => safe.into_owned().into()
         }
     }
-}
 
-impl CheckKeyword<String> for &str {
-    fn is_keyword(&self) -> bool {
-        KEYWORDS.contains(self)
+    fn into_safe_in_all(self, sets: &[&dyn KeywordSet]) -> Result<Self, ConflictError> {
+        match set::into_safe_in_all(self.as_ref(), sets)? {
+            Cow::Borrowed(safe) if safe.len() == self.as_ref().len() => Ok(self),
+            safe => Ok(safe.into_owned().into())
+        }
     }
 
-    fn into_safe(self) -> String {
-        if self.is_keyword() {
-            let safe = format!("r#{}", self);
-            safe.into()
+    fn try_into_safe_for(self, edition: Edition) -> Result<Self, IdentError> {
+        let safe = ident::check_escapable(self.as_ref(), edition)?;
+        if safe.is_same_as(self.as_ref()) {
+            Ok(self)
         } else {
-            self.into()
-        } 
+            Ok(safe.to_string().into())
+        }
+    }
+
+    fn into_ident_with(self, options: &SanitizeOptions<'_>) -> Self {
+        sanitize::sanitize(self.as_ref(), options).into()
+    }
+
+    fn encode_ident(self) -> Self {
+        match encode::encode(self.as_ref()) {
+            Cow::Borrowed(encoded) if encoded.len() == self.as_ref().len() => self,
+            encoded => encoded.into_owned().into()
+        }
+    }
+
+    fn decode_ident(self) -> Result<Self, DecodeIdentError> {
+        match encode::decode(self.as_ref())? {
+            Cow::Borrowed(decoded) if decoded.len() == self.as_ref().len() => Ok(self),
+            decoded => Ok(decoded.into_owned().into())
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
+impl CheckKeyword<String> for &str {}
+
+impl CheckKeyword<str> for str {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +78,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn into_safe() {
         assert_eq!(String::from("match").into_safe(), "r#match");
         assert_eq!("asdf".into_safe(), "asdf");
@@ -55,4 +91,129 @@ mod tests {
             }
         )
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn is_keyword_in() {
+        assert!("match".is_keyword_in(Edition::Edition2015));
+        assert!(!"async".is_keyword_in(Edition::Edition2015));
+        assert!(String::from("async").is_keyword_in(Edition::Edition2018));
+        assert!("try".is_keyword_in(Edition::Edition2021));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_for() {
+        assert_eq!("dyn".into_safe_for(Edition::Edition2015), "dyn");
+        assert_eq!("dyn".into_safe_for(Edition::Edition2018), "r#dyn");
+        assert_eq!(String::from("try").into_safe_for(Edition::Edition2024), "r#try");
+        assert_eq!("gen".into_safe_for(Edition::Edition2021), "gen");
+        assert_eq!("gen".into_safe_for(Edition::Edition2024), "r#gen");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn non_raw() {
+        assert_eq!("self".into_safe(), "self_");
+        assert_eq!(String::from("Self").into_safe(), "Self_");
+        assert_eq!("super".into_safe(), "super_");
+        assert_eq!("crate".into_safe(), "crate_");
+        assert_eq!("_".into_safe(), "__");
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!("crate".escape(), Escape::TrailingUnderscore);
+        assert_eq!(String::from("fn").escape(), Escape::Raw);
+        assert_eq!("self_".escape(), Escape::TrailingUnderscore);
+        assert_eq!("__".escape(), Escape::TrailingUnderscore);
+        assert_eq!("selfish".escape(), Escape::Unchanged);
+        assert_eq!("".escape(), Escape::Unchanged);
+        assert_eq!("gen".escape_in(Edition::Edition2024), Escape::Raw);
+    }
+
+    #[test]
+    fn raw() {
+        assert!("r#match".is_raw());
+        assert!(!String::from("match").is_raw());
+        assert_eq!("r#match".unraw(), "match");
+        assert_eq!(String::from("r#foo").unraw(), "foo");
+        assert_eq!("foo".unraw(), "foo");
+
+        assert!(!"r#match".is_keyword());
+        assert_eq!("r#match".escape(), Escape::Raw);
+        assert_eq!("r#foo".escape(), Escape::Unchanged);
+        assert_eq!("r#self".escape(), Escape::TrailingUnderscore);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_raw() {
+        assert_eq!("r#match".into_safe(), "r#match");
+        assert_eq!(String::from("r#match").into_safe(), "r#match");
+        assert_eq!("r#foo".into_safe(), "foo");
+        assert_eq!(String::from("r#self").into_safe(), "self_");
+        assert_eq!("r#r#match".into_safe(), "r#match");
+        assert_eq!(String::from("r#r#r#foo").into_safe(), "foo");
+        assert_eq!("r#".into_safe(), "r#");
+        assert_eq!("r#r#".into_safe(), "r#");
+        assert_eq!("r#gen".into_safe_for(Edition::Edition2021), "gen");
+    }
+
+    #[test]
+    fn original_name() {
+        assert_eq!("r#match".original_name(), "match");
+        assert_eq!(String::from("r#gen").original_name(), "gen");
+        assert_eq!("self_".original_name(), "self");
+        assert_eq!("crate___".original_name(), "crate__");
+        assert_eq!("__".original_name(), "_");
+        assert_eq!("___".original_name(), "__");
+        assert_eq!("_".original_name(), "_");
+        assert_eq!("self".original_name(), "self");
+        assert_eq!("foo_".original_name(), "foo_");
+    }
+
+    #[test]
+    fn weak_keywords() {
+        assert!("union".is_weak_keyword());
+        assert!(!"union".is_keyword());
+        assert!(String::from("'static").is_weak_keyword());
+        assert!("macro_rules".is_weak_keyword());
+        assert!("raw".is_weak_keyword());
+        assert!("safe".is_weak_keyword());
+        assert!(!"match".is_weak_keyword());
+
+        assert!("dyn".is_weak_keyword_in(Edition::Edition2015));
+        assert!(!"dyn".is_weak_keyword_in(Edition::Edition2018));
+
+        assert_eq!("dyn".keyword_kind_in(Edition::Edition2015), Some(KeywordKind::Weak));
+        assert_eq!("dyn".keyword_kind_in(Edition::Edition2018), Some(KeywordKind::Strict));
+        assert_eq!("abstract".keyword_kind(), Some(KeywordKind::Reserved));
+        assert_eq!(String::from("hello").keyword_kind(), None);
+    }
+
+    #[test]
+    fn editions() {
+        let editions = [
+            Edition::Edition2015,
+            Edition::Edition2018,
+            Edition::Edition2021,
+            Edition::Edition2024
+        ];
+
+        let expected = [
+            ("match", [true, true, true, true]),
+            ("async", [false, true, true, true]),
+            ("await", [false, true, true, true]),
+            ("dyn", [false, true, true, true]),
+            ("try", [false, true, true, true]),
+            ("gen", [false, false, false, true]),
+            ("hello", [false, false, false, false]),
+        ];
+
+        for (word, answers) in expected {
+            for (edition, answer) in editions.iter().zip(answers) {
+                assert_eq!(word.is_keyword_in(*edition), answer, "{} in {:?}", word, edition);
+            }
+        }
+    }
+}
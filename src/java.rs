@@ -0,0 +1,120 @@
+//! Java and Kotlin keywords. Java's contextual keywords like `record` and `var` are kept separate,
+//! since they can still be used as most names, and Kotlin quotes its keywords with backticks.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::java::{Java, Kotlin};
+//!
+//! assert_eq!("class".into_safe_in_set(&Java::KEYWORDS), "class_");
+//! assert_eq!("record".into_safe_in_set(&Java::CONTEXTUAL), "record_");
+//! assert_eq!("object".into_safe_in_set(&Kotlin), "`object`");
+//! ```
+
+use super::*;
+
+/// Java's reserved keywords and literals.
+pub const JAVA_KEYWORDS: &[&str] = &[
+    "_", "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const",
+    "continue", "default", "do", "double", "else", "enum", "extends", "false", "final", "finally",
+    "float", "for", "goto", "if", "implements", "import", "instanceof", "int", "interface", "long",
+    "native", "new", "null", "package", "private", "protected", "public", "return", "short",
+    "static", "strictfp", "super", "switch", "synchronized", "this", "throw", "throws", "transient",
+    "true", "try", "void", "volatile", "while",
+];
+
+/// Java's contextual keywords, which are only keywords in certain places, like `var` and `record`.
+pub const JAVA_CONTEXTUAL_KEYWORDS: &[&str] = &[
+    "exports", "module", "open", "opens", "permits", "provides", "record", "requires", "sealed",
+    "to", "transitive", "uses", "var", "when", "with", "yield",
+];
+
+/// Kotlin's hard keywords, which can't be used as names without backticks.
+pub const KOTLIN_KEYWORDS: &[&str] = &[
+    "as", "break", "class", "continue", "do", "else", "false", "for", "fun", "if", "in",
+    "interface", "is", "null", "object", "package", "return", "super", "this", "throw", "true",
+    "try", "typealias", "typeof", "val", "var", "when", "while",
+];
+
+/// Checks if `name` is a Java keyword or literal.
+pub fn is_java_keyword(name: &str) -> bool {
+    JAVA_KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` is a Java contextual keyword.
+pub fn is_java_contextual_keyword(name: &str) -> bool {
+    JAVA_CONTEXTUAL_KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` is a Kotlin hard keyword.
+pub fn is_kotlin_keyword(name: &str) -> bool {
+    KOTLIN_KEYWORDS.contains(&name)
+}
+
+/// A [KeywordSet] of Java names, escaped with a trailing underscore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Java {
+    /// Also escape contextual keywords.
+    pub contextual: bool,
+}
+
+impl Java {
+    /// Only reserved keywords and literals.
+    pub const KEYWORDS: Java = Java { contextual: false };
+    /// Reserved and contextual keywords.
+    pub const CONTEXTUAL: Java = Java { contextual: true };
+}
+
+impl KeywordSet for Java {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_java_keyword(name) || (self.contextual && is_java_contextual_keyword(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::TrailingUnderscore
+    }
+}
+
+/// A [KeywordSet] of Kotlin hard keywords, escaped with backticks.
+///
+/// Soft keywords and modifiers can be used as names, so they aren't included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Kotlin;
+
+impl KeywordSet for Kotlin {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_kotlin_keyword(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Affix { prefix: "`", suffix: "`" }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_java_keyword("goto"));
+        assert!(is_java_keyword("null"));
+        assert!(!is_java_keyword("var"));
+        assert!(is_java_contextual_keyword("var"));
+        assert!(is_kotlin_keyword("fun"));
+        assert!(!is_kotlin_keyword("data"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("int".into_safe_in_set(&Java::KEYWORDS), "int_");
+        assert_eq!("_".into_safe_in_set(&Java::KEYWORDS), "__");
+        assert_eq!("yield".into_safe_in_set(&Java::KEYWORDS), "yield");
+        assert_eq!("yield".into_safe_in_set(&Java::CONTEXTUAL), "yield_");
+        assert_eq!("val".into_safe_in_set(&Kotlin), "`val`");
+        assert_eq!(String::from("when").into_safe_in_set(&Kotlin), "`when`");
+        assert_eq!("data".into_safe_in_set(&Kotlin), "data");
+    }
+}
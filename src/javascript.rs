@@ -0,0 +1,245 @@
+//! JavaScript and TypeScript reserved words, including the ones only reserved in strict mode code
+//! and TypeScript's contextual keywords like `type`.
+//!
+//! Names are escaped differently depending on where they're used. Bindings (variables, parameters,
+//! functions) can't be reserved words at all, so they get a trailing underscore. Property names
+//! are quoted instead, which also works for names that aren't identifiers.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::javascript::{JavaScript, TypeScript};
+//!
+//! assert_eq!("delete".into_safe_in_set(&JavaScript::STRICT), "delete_");
+//! assert_eq!("delete".into_safe_in_set(&JavaScript::PROPERTY), "\"delete\"");
+//! assert_eq!("my-field".into_safe_in_set(&TypeScript::PROPERTY), "\"my-field\"");
+//! assert_eq!("let".into_safe_in_set(&JavaScript::SLOPPY), "let");
+//! ```
+
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
+/// Words that are always reserved in ECMAScript 2015 and later.
+pub const RESERVED_WORDS: &[&str] = &[
+    "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do",
+    "else", "enum", "export", "extends", "false", "finally", "for", "function", "if", "import", "in",
+    "instanceof", "new", "null", "return", "super", "switch", "this", "throw", "true", "try",
+    "typeof", "var", "void", "while", "with",
+];
+
+/// Words that are reserved in strict mode code, which includes modules and classes.
+///
+/// `await` is only reserved in modules, and `eval` and `arguments` can't be used as bindings in
+/// strict mode, so they're included too.
+pub const STRICT_RESERVED_WORDS: &[&str] = &[
+    "arguments", "await", "eval", "implements", "interface", "let", "package", "private",
+    "protected", "public", "static", "yield",
+];
+
+/// TypeScript's contextual keywords, which can be used as bindings, but may be confusing,
+/// and some of which (like `string`) can't be used as type names.
+pub const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "abstract", "accessor", "any", "as", "asserts", "async", "bigint", "boolean", "constructor",
+    "declare", "from", "get", "global", "infer", "intrinsic", "is", "keyof", "module", "namespace",
+    "never", "number", "object", "of", "out", "override", "readonly", "require", "satisfies", "set",
+    "string", "symbol", "type", "undefined", "unique", "unknown", "using",
+];
+
+/// Checks if `name` is always reserved.
+pub fn is_reserved_word(name: &str) -> bool {
+    RESERVED_WORDS.contains(&name)
+}
+
+/// Checks if `name` is only reserved in strict mode code.
+pub fn is_strict_reserved_word(name: &str) -> bool {
+    STRICT_RESERVED_WORDS.contains(&name)
+}
+
+/// Checks if `name` is a TypeScript contextual keyword.
+pub fn is_typescript_keyword(name: &str) -> bool {
+    TYPESCRIPT_KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` can be written as an identifier, ignoring reserved words.
+///
+/// This uses the same Unicode properties as Rust, plus `$`.
+pub fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c == '$' || c == '_' || unicode_ident::is_xid_start(c) => {
+            chars.all(|c| c == '$' || unicode_ident::is_xid_continue(c))
+        }
+        _ => false
+    }
+}
+
+/// Wraps `name` in double quotes, escaping any quotes, backslashes, and line breaks in it.
+///
+/// Control characters and the line and paragraph separators (U+2028 and U+2029) are written as
+/// `\u{..}` escapes, so the string literal stays on one line.
+#[cfg(feature = "alloc")]
+pub fn quote(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() || c == '\u{2028}' || c == '\u{2029}' => {
+                write!(quoted, "\\u{{{:x}}}", c as u32).unwrap()
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Where a name is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Position {
+    /// A variable, parameter, function, or class name. Escaped with a trailing underscore.
+    #[default]
+    Binding,
+    /// An object property name. Quoted, and also quoted if it isn't an identifier.
+    Property,
+}
+
+/// A [KeywordSet] of JavaScript reserved words.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct JavaScript {
+    /// Also reserve the strict mode reserved words.
+    pub strict: bool,
+    pub position: Position,
+}
+
+impl JavaScript {
+    /// Bindings in sloppy mode scripts.
+    pub const SLOPPY: JavaScript = JavaScript { strict: false, position: Position::Binding };
+    /// Bindings in strict mode code and modules.
+    pub const STRICT: JavaScript = JavaScript { strict: true, position: Position::Binding };
+    /// Object property names.
+    pub const PROPERTY: JavaScript = JavaScript { strict: true, position: Position::Property };
+}
+
+impl KeywordSet for JavaScript {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_reserved_word(name)
+            || (self.strict && is_strict_reserved_word(name))
+            || (self.position == Position::Property && !is_identifier_name(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        escape_strategy(self.position)
+    }
+}
+
+/// A [KeywordSet] of TypeScript reserved words. TypeScript is always treated as strict mode code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct TypeScript {
+    /// Also reserve the contextual keywords.
+    pub contextual: bool,
+    pub position: Position,
+}
+
+impl TypeScript {
+    /// Bindings.
+    pub const BINDING: TypeScript = TypeScript { contextual: false, position: Position::Binding };
+    /// Bindings, avoiding contextual keywords too.
+    pub const CONTEXTUAL: TypeScript = TypeScript { contextual: true, position: Position::Binding };
+    /// Property names, in interfaces and object types.
+    pub const PROPERTY: TypeScript = TypeScript { contextual: false, position: Position::Property };
+}
+
+impl KeywordSet for TypeScript {
+    fn is_reserved(&self, name: &str) -> bool {
+        JavaScript { strict: true, position: self.position }.is_reserved(name)
+            || (self.contextual && is_typescript_keyword(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        escape_strategy(self.position)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn escape_strategy(position: Position) -> EscapeStrategy<'static> {
+    match position {
+        Position::Binding => EscapeStrategy::TrailingUnderscore,
+        Position::Property => EscapeStrategy::Custom(&quote),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_words() {
+        assert!(is_reserved_word("delete"));
+        assert!(!is_reserved_word("let"));
+        assert!(is_strict_reserved_word("let"));
+        assert!(is_strict_reserved_word("implements"));
+        assert!(is_typescript_keyword("readonly"));
+
+        assert!(is_identifier_name("$el"));
+        assert!(is_identifier_name("café"));
+        assert!(!is_identifier_name("my-field"));
+        assert!(!is_identifier_name("1st"));
+        assert!(!is_identifier_name(""));
+    }
+
+    #[test]
+    fn is_keyword_in_set() {
+        assert!("class".is_keyword_in_set(&JavaScript::SLOPPY));
+        assert!(!"static".is_keyword_in_set(&JavaScript::SLOPPY));
+        assert!("static".is_keyword_in_set(&JavaScript::STRICT));
+        assert!(!"my-field".is_keyword_in_set(&JavaScript::STRICT));
+        assert!("my-field".is_keyword_in_set(&JavaScript::PROPERTY));
+
+        assert!("interface".is_keyword_in_set(&TypeScript::BINDING));
+        assert!(!"keyof".is_keyword_in_set(&TypeScript::BINDING));
+        assert!("keyof".is_keyword_in_set(&TypeScript::CONTEXTUAL));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("new".into_safe_in_set(&JavaScript::STRICT), "new_");
+        assert_eq!(String::from("new").into_safe_in_set(&JavaScript::PROPERTY), "\"new\"");
+        assert_eq!("name".into_safe_in_set(&JavaScript::PROPERTY), "name");
+        assert_eq!("say \"hi\"".into_safe_in_set(&TypeScript::PROPERTY), "\"say \\\"hi\\\"\"");
+        assert_eq!("type".into_safe_in_set(&TypeScript::CONTEXTUAL), "type_");
+        assert_eq!("type".into_safe_in_set(&TypeScript::BINDING), "type");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all() {
+        let targets = [&TypeScript::PROPERTY as &dyn KeywordSet, &RustKeywords::EDITION_2021];
+        assert_eq!("my-field".into_safe_in_all(&targets).unwrap(), "my_field");
+        assert_eq!("type".into_safe_in_all(&targets).unwrap(), "type_");
+        assert_eq!("delete".into_safe_in_all(&targets).unwrap(), "delete_");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn quote() {
+        assert_eq!(super::quote("a\\b"), "\"a\\\\b\"");
+        assert_eq!(super::quote("a\nb\r\tc"), "\"a\\nb\\r\\tc\"");
+        assert_eq!(super::quote("a\u{0}b\u{7f}"), "\"a\\u{0}b\\u{7f}\"");
+        assert_eq!(super::quote("a\u{2028}b\u{2029}"), "\"a\\u{2028}b\\u{2029}\"");
+        assert_eq!(super::quote("café"), "\"café\"");
+        assert_eq!("a\nb".into_safe_in_set(&JavaScript::PROPERTY), "\"a\\nb\"");
+    }
+}
@@ -0,0 +1,232 @@
+use super::*;
+
+use core::fmt;
+use core::str::FromStr;
+
+keywords! {
+    // STRICT, 2015
+
+    As: "as", Strict, Edition2015, (1, 0);
+    Break: "break", Strict, Edition2015, (1, 0);
+    Const: "const", Strict, Edition2015, (1, 0);
+    Continue: "continue", Strict, Edition2015, (1, 0);
+    Crate: "crate", Strict, Edition2015, (1, 0);
+    Else: "else", Strict, Edition2015, (1, 0);
+    Enum: "enum", Strict, Edition2015, (1, 0);
+    Extern: "extern", Strict, Edition2015, (1, 0);
+    False: "false", Strict, Edition2015, (1, 0);
+    Fn: "fn", Strict, Edition2015, (1, 0);
+    For: "for", Strict, Edition2015, (1, 0);
+    If: "if", Strict, Edition2015, (1, 0);
+    Impl: "impl", Strict, Edition2015, (1, 0);
+    In: "in", Strict, Edition2015, (1, 0);
+    Let: "let", Strict, Edition2015, (1, 0);
+    Loop: "loop", Strict, Edition2015, (1, 0);
+    Match: "match", Strict, Edition2015, (1, 0);
+    Mod: "mod", Strict, Edition2015, (1, 0);
+    Move: "move", Strict, Edition2015, (1, 0);
+    Mut: "mut", Strict, Edition2015, (1, 0);
+    Pub: "pub", Strict, Edition2015, (1, 0);
+    Ref: "ref", Strict, Edition2015, (1, 0);
+    Return: "return", Strict, Edition2015, (1, 0);
+    SelfLower: "self", Strict, Edition2015, (1, 0);
+    SelfUpper: "Self", Strict, Edition2015, (1, 0);
+    Static: "static", Strict, Edition2015, (1, 0);
+    Struct: "struct", Strict, Edition2015, (1, 0);
+    Super: "super", Strict, Edition2015, (1, 0);
+    Trait: "trait", Strict, Edition2015, (1, 0);
+    True: "true", Strict, Edition2015, (1, 0);
+    Type: "type", Strict, Edition2015, (1, 0);
+    Unsafe: "unsafe", Strict, Edition2015, (1, 0);
+    Use: "use", Strict, Edition2015, (1, 0);
+    Where: "where", Strict, Edition2015, (1, 0);
+    While: "while", Strict, Edition2015, (1, 0);
+    Underscore: "_", Strict, Edition2015, (1, 0);
+
+    // STRICT, 2018
+
+    Async: "async", Strict, Edition2018, (1, 31);
+    Await: "await", Strict, Edition2018, (1, 31);
+    Dyn: "dyn", Strict, Edition2018, (1, 27), Weak;
+
+    // RESERVED, 2015
+
+    Abstract: "abstract", Reserved, Edition2015, (1, 0);
+    Become: "become", Reserved, Edition2015, (1, 0);
+    Box: "box", Reserved, Edition2015, (1, 0);
+    Do: "do", Reserved, Edition2015, (1, 0);
+    Final: "final", Reserved, Edition2015, (1, 0);
+    Macro: "macro", Reserved, Edition2015, (1, 0);
+    Override: "override", Reserved, Edition2015, (1, 0);
+    Priv: "priv", Reserved, Edition2015, (1, 0);
+    Typeof: "typeof", Reserved, Edition2015, (1, 0);
+    Unsized: "unsized", Reserved, Edition2015, (1, 0);
+    Virtual: "virtual", Reserved, Edition2015, (1, 0);
+    Yield: "yield", Reserved, Edition2015, (1, 0);
+
+    // RESERVED, 2018
+
+    Try: "try", Reserved, Edition2018, (1, 31);
+
+    // RESERVED, 2024
+
+    Gen: "gen", Reserved, Edition2024, (1, 85);
+
+    // WEAK, 2015
+
+    StaticLifetime: "'static", Weak, Edition2015, (1, 0);
+    MacroRules: "macro_rules", Weak, Edition2015, (1, 0);
+    Raw: "raw", Weak, Edition2015, (1, 82);
+    Safe: "safe", Weak, Edition2015, (1, 82);
+    Union: "union", Weak, Edition2015, (1, 19);
+}
+
+/// Size of the lookup table. Must be a power of two.
+const TABLE_LEN: usize = 512;
+
+/// Length of the longest keyword. Anything longer can be rejected without hashing.
+const MAX_LEN: usize = {
+    let mut max = 0;
+    let mut i = 0;
+    while i < Keyword::ALL.len() {
+        let len = Keyword::ALL[i].as_str().len();
+        if len > max {
+            max = len;
+        }
+        i += 1;
+    }
+    max
+};
+
+/// FNV-1a, with a final mix so the low bits can be used as the index.
+const fn hash(bytes: &[u8], seed: u32) -> usize {
+    let mut hash = seed ^ 0x811c_9dc5;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x2c1b_3c6d);
+    hash ^= hash >> 12;
+    hash as usize & (TABLE_LEN - 1)
+}
+
+/// Puts each keyword at the index of its hash, or returns `None` if two of them collide.
+const fn build_table(seed: u32) -> Option<[Option<Keyword>; TABLE_LEN]> {
+    let mut table = [None; TABLE_LEN];
+    let mut i = 0;
+    while i < Keyword::ALL.len() {
+        let keyword = Keyword::ALL[i];
+        let index = hash(keyword.as_str().as_bytes(), seed);
+        if table[index].is_some() {
+            return None;
+        }
+        table[index] = Some(keyword);
+        i += 1;
+    }
+    Some(table)
+}
+
+/// A perfect hash table of the keywords, using the first seed that doesn't have any collisions.
+const TABLE: (u32, [Option<Keyword>; TABLE_LEN]) = {
+    let mut seed = 0;
+    loop {
+        if let Some(table) = build_table(seed) {
+            break (seed, table);
+        }
+        seed += 1;
+        assert!(seed < 1_000, "couldn't find a seed without collisions");
+    }
+};
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl Keyword {
+    /// Looks up the keyword spelled `name`, in constant time.
+    pub(crate) const fn lookup(name: &str) -> Option<Keyword> {
+        let bytes = name.as_bytes();
+        if bytes.len() > MAX_LEN {
+            return None;
+        }
+        let (seed, table) = &TABLE;
+        match table[hash(bytes, *seed)] {
+            Some(keyword) if bytes_eq(keyword.as_str().as_bytes(), bytes) => Some(keyword),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Keyword {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when parsing a [Keyword] from a string that isn't one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseKeywordError;
+
+impl fmt::Display for ParseKeywordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a keyword")
+    }
+}
+
+impl core::error::Error for ParseKeywordError {}
+
+impl FromStr for Keyword {
+    type Err = ParseKeywordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Keyword::lookup(s).ok_or(ParseKeywordError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for &keyword in Keyword::ALL {
+            assert_eq!(keyword.as_str().parse(), Ok(keyword));
+            assert_eq!(keyword.to_string(), keyword.as_str());
+        }
+        assert_eq!("hello".parse::<Keyword>(), Err(ParseKeywordError));
+    }
+
+    #[test]
+    fn lookup() {
+        for &keyword in Keyword::ALL {
+            assert_eq!(Keyword::lookup(keyword.as_str()), Some(keyword));
+        }
+        for name in ["", "a", "hello", "matc", "matches", "Match", "selF", "r#match", "static'"] {
+            assert_eq!(Keyword::lookup(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!(Keyword::Match.kind(), KeywordKind::Strict);
+        assert_eq!(Keyword::Gen.introduced_in(), Edition::Edition2024);
+        assert_eq!(Keyword::Union.rustc_version_reserved(), (1, 19));
+
+        assert_eq!(Keyword::Dyn.kind(), KeywordKind::Strict);
+        assert_eq!(Keyword::Dyn.introduced_in(), Edition::Edition2018);
+        assert_eq!(Keyword::Dyn.kind_in(Edition::Edition2015), Some(KeywordKind::Weak));
+        assert_eq!(Keyword::Async.kind_in(Edition::Edition2015), None);
+    }
+}
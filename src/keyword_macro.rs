@@ -0,0 +1,61 @@
+/// Defines the [Keyword](crate::Keyword) enum and its metadata from a single table.
+///
+/// Each row is `Variant: "text", kind, edition, (major, minor)`, optionally followed by
+/// the kind it had before `edition`.
+macro_rules! keywords {
+    ($($variant:ident: $text:literal, $kind:ident, $edition:ident, ($major:literal, $minor:literal) $(, $before:ident)?;)*) => {
+        /// A keyword, with some metadata about it.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum Keyword {
+            $(
+                #[doc = concat!("`", $text, "`")]
+                $variant,
+            )*
+        }
+
+        impl Keyword {
+            /// Every keyword, including weak keywords, in no particular order.
+            pub const ALL: &'static [Keyword] = &[$(Keyword::$variant),*];
+
+            /// The keyword as it is written in source code.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Keyword::$variant => $text,)*
+                }
+            }
+
+            /// The kind of keyword it is in the latest edition.
+            pub const fn kind(self) -> KeywordKind {
+                match self {
+                    $(Keyword::$variant => KeywordKind::$kind,)*
+                }
+            }
+
+            /// The edition in which it became the kind returned by [Keyword::kind].
+            pub const fn introduced_in(self) -> Edition {
+                match self {
+                    $(Keyword::$variant => Edition::$edition,)*
+                }
+            }
+
+            /// The `(major, minor)` version of the first stable rustc that reserved it in any edition.
+            pub const fn rustc_version_reserved(self) -> (u32, u32) {
+                match self {
+                    $(Keyword::$variant => ($major, $minor),)*
+                }
+            }
+
+            /// The kind of keyword it is in the given edition, if it is one at all.
+            pub const fn kind_in(self, edition: Edition) -> Option<KeywordKind> {
+                if edition as u8 >= self.introduced_in() as u8 {
+                    return Some(self.kind());
+                }
+                match self {
+                    $($(Keyword::$variant => Some(KeywordKind::$before),)?)*
+                    #[allow(unreachable_patterns)]
+                    _ => None
+                }
+            }
+        }
+    }
+}
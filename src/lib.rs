@@ -2,6 +2,7 @@
 //! and convert it to a safe non-keyword if so.
 //!
 //! Only strict and reserved keywords are checked against; weak keywords are not included.
+//! Weak keywords can be checked separately with [CheckKeyword::is_weak_keyword] and [CheckKeyword::keyword_kind].
 //!
 //! You can add this dependency with:
 //!
@@ -13,17 +14,159 @@
 //! # Example
 //!
 //! ```
+//! # #[cfg(feature = "alloc")] {
 //! use check_keyword::CheckKeyword;
 //! let keyword = "match";
 //!
 //! assert!(keyword.is_keyword());
 //! assert_eq!(keyword.into_safe(), "r#match");
+//! # }
 //! ```
 //! 
 //! The [CheckKeyword::into_safe] method automatically checks [CheckKeyword::is_keyword] for you.
 //! You don't need to call [CheckKeyword::is_keyword]
 //! if you don't care whether it was originally a keyword or not.
+//!
+//! # Non-raw Keywords
+//!
+//! A few keywords (`self`, `Self`, `super`, `crate`, and `_`) can't be used as raw identifiers,
+//! so `r#self` won't compile. [CheckKeyword::into_safe] adds an underscore to the end of these instead.
+//! [CheckKeyword::escape] tells you which of the two was used.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::{CheckKeyword, Escape};
+//!
+//! assert_eq!("self".into_safe(), "self_");
+//! assert_eq!("self".escape(), Escape::TrailingUnderscore);
+//! assert_eq!("match".escape(), Escape::Raw);
+//! assert_eq!("hello".escape(), Escape::Unchanged);
+//! # }
+//! ```
+//!
+//! So that this can be undone with [CheckKeyword::original_name], names that are one of these
+//! keywords followed by underscores also get another underscore, like `self_` to `self__`.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::CheckKeyword;
+//!
+//! assert_eq!("self_".into_safe(), "self__");
+//! assert_eq!("self_".into_safe().original_name(), "self_");
+//! assert_eq!("match".into_safe().original_name(), "match");
+//! # }
+//! ```
+//!
+//! If you would rather get an error, use [CheckKeyword::try_into_safe], which also checks that
+//! the string is a valid identifier in the first place.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::{CheckKeyword, IdentError};
+//!
+//! assert_eq!("self".try_into_safe(), Err(IdentError::NonRawKeyword));
+//! assert_eq!("foo-bar".try_into_safe(), Err(IdentError::InvalidContinue('-')));
+//! # }
+//! ```
 //! 
+//! # Identifiers
+//!
+//! [CheckKeyword::is_valid_ident] checks the rest of the identifier rules too, so you don't need
+//! a separate crate to validate names.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//!
+//! assert!("naïve".is_valid_ident());
+//! assert!("r#match".is_valid_ident());
+//! assert!(!"match".is_valid_ident());
+//! assert!(!"2fast".is_valid_ident());
+//! assert!(!"my-field".is_valid_ident());
+//! ```
+//!
+//! To turn any string into a valid identifier, use [CheckKeyword::into_ident].
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::CheckKeyword;
+//!
+//! assert_eq!("my-field".into_ident(), "my_field");
+//! assert_eq!("2fast".into_ident(), "_2fast");
+//! # }
+//! ```
+//!
+//! That can't be undone, since different strings can end up the same. If you need to get the
+//! original string back, use [CheckKeyword::encode_ident] and [CheckKeyword::decode_ident] instead.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::CheckKeyword;
+//!
+//! assert_eq!("my-field".encode_ident(), "my_2d_field");
+//! assert_eq!("my_field".encode_ident(), "my__field");
+//! assert_eq!("my_2d_field".decode_ident().unwrap(), "my-field");
+//! # }
+//! ```
+//!
+//! # Keywords
+//!
+//! The full list of keywords is available as [Keyword::ALL], along with some metadata about each one.
+//!
+//! ```
+//! use check_keyword::{Edition, Keyword, KeywordKind};
+//!
+//! let keyword: Keyword = "async".parse().unwrap();
+//! assert_eq!(keyword.kind(), KeywordKind::Strict);
+//! assert_eq!(keyword.introduced_in(), Edition::Edition2018);
+//! ```
+//!
+//! Keywords can also be checked at compile time with [is_keyword_const] and [assert_not_keyword].
+//!
+//! # Keyword Sets
+//!
+//! If you need to escape more words than just the Rust keywords, you can make a [KeywordSet]:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::{CheckKeyword, KeywordSet, RustKeywords};
+//!
+//! let set = RustKeywords::EDITION_2021.with(["Error", "Result"]);
+//!
+//! assert!("Error".is_keyword_in_set(&set));
+//! assert_eq!("Result".into_safe_in_set(&set), "r#Result");
+//! # }
+//! ```
+//!
+//! Keyword sets for other languages are available behind features:
+//!
+//! - `c`: [c::C] and [c::Cpp]
+//! - `csharp`: [csharp::CSharp]
+//! - `go`: [go::Go]
+//! - `java`: [java::Java] and [java::Kotlin]
+//! - `javascript`: [javascript::JavaScript] and [javascript::TypeScript]
+//! - `python`: [python::Python]
+//! - `sql`: [sql::Dialect]
+//! - `swift`: [swift::Swift]
+//!
+//! To pick one name that's safe in several languages at once, see [CheckKeyword::conflicts_in]
+//! and [CheckKeyword::into_safe_in_all].
+//!
+//! # Name Scopes
+//!
+//! Escaping a name doesn't know about the other names around it, so two different inputs can
+//! end up the same. A [NameScope] remembers the names it has handed out and numbers the duplicates:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::NameScope;
+//!
+//! let mut scope = NameScope::new();
+//!
+//! assert_eq!(scope.allocate("type"), "r#type");
+//! assert_eq!(scope.allocate("type"), "type_1");
+//! # }
+//! ```
+//!
 //! # Implementations
 //! 
 //! There is a special implementation of `CheckKeyword<String>` for [&str], and a
@@ -32,106 +175,681 @@
 //! The blanket implementation covers [String], and is only tested for that, but should
 //! cover any other String-like type as well. I can try to broaden the definition to fit
 //! other types if needed (open an issue).
+//!
+//! Lastly, there is an implementation of `CheckKeyword<str>` for [str], which only has the methods
+//! that don't allocate. Without the `alloc` feature it's the only one, and anything that derefs to
+//! [str] can use it, like `"match".is_keyword()`. Enabling `alloc` only adds implementations, so
+//! it doesn't break code written without it.
+//!
+//! The implementation for [&str] always allocates a new [String]. If you'd rather borrow the
+//! input when it doesn't need to be escaped, use [into_safe_cow].
 //! 
 //! # Rust Editions
 //!
-//! By default, the keywords added in Rust Edition 2018 are included in the list of checked keywords.
-//! This can be disabled with `default-features = false` in your Cargo.toml.
+//! Each method has a variant that takes an [Edition] to check against, like
+//! [CheckKeyword::is_keyword_in] and [CheckKeyword::into_safe_for]:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")] {
+//! use check_keyword::{CheckKeyword, Edition};
+//!
+//! assert!(!"async".is_keyword_in(Edition::Edition2015));
+//! assert!("async".is_keyword_in(Edition::Edition2018));
+//! assert_eq!("async".into_safe_for(Edition::Edition2018), "r#async");
+//! # }
+//! ```
+//!
+//! The methods without an edition argument use [Edition::default]. That is Rust 2018, unless the
+//! `2018` feature is disabled with `default-features = false` in your Cargo.toml, in which case it is Rust 2015.
 //!
 //! ```toml
 //! [dependencies]
-//! check_keyword = { version = "0.2", default-features = false }
+//! check_keyword = { version = "0.2", default-features = false, features = ["alloc"] }
 //! ```
 //!
 //! Future Rust editions may add new keywords, and this crate will be updated to reflect that.
 //! (Or you can create an issue on github if I don't.)
-mod impls;
+//!
+//! # no_std
+//!
+//! This crate is `no_std`. The methods that return a new string, like [CheckKeyword::into_safe],
+//! need the `alloc` feature, which is enabled by default. Without it, you can still check keywords,
+//! and write the escaped name into a fixed buffer with [CheckKeyword::write_safe] or
+//! anywhere else with [CheckKeyword::safe_display].
+//!
+//! ```toml
+//! [dependencies]
+//! check_keyword = { version = "0.2", default-features = false, features = ["2018"] }
+//! ```
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[macro_use] mod arr_macro;
+#[macro_use] mod keyword_macro;
+
+mod display;
+#[cfg(feature = "alloc")]
+mod encode;
+mod impls;
+mod ident;
+mod keyword;
+#[cfg(feature = "alloc")]
+mod sanitize;
+#[cfg(feature = "alloc")]
+mod scope;
+mod set;
+
+#[cfg(feature = "c")]
+pub mod c;
+#[cfg(feature = "csharp")]
+pub mod csharp;
+#[cfg(feature = "go")]
+pub mod go;
+#[cfg(feature = "java")]
+pub mod java;
+#[cfg(feature = "javascript")]
+pub mod javascript;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "sql")]
+pub mod sql;
+#[cfg(feature = "swift")]
+pub mod swift;
+#[cfg(feature = "alloc")]
+mod strategy;
+
+pub use display::SafeDisplay;
+#[cfg(feature = "alloc")]
+pub use encode::DecodeIdentError;
+pub use ident::IdentError;
+pub use keyword::{Keyword, ParseKeywordError};
+#[cfg(feature = "alloc")]
+pub use sanitize::SanitizeOptions;
+#[cfg(feature = "alloc")]
+pub use scope::NameScope;
+pub use set::{Conflicts, KeywordSet, RustKeywords, Union};
+#[cfg(feature = "alloc")]
+pub use set::{ConflictError, WithStrategy};
+#[cfg(feature = "alloc")]
+pub use strategy::EscapeStrategy;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::{String, ToString}};
 
 /// The main trait.
 /// 
 /// The generic argument `T` is the output type of `into_safe`, and in the blanket implementation
 /// is equal to `Self`. I would have used an associated type,
 /// but I ran into the good-old "upstream crates may add new impl of trait" error when implementing [str].
-pub trait CheckKeyword<T> {
-    /// Checks if `self` is a keyword.
-    fn is_keyword(&self) -> bool;
+///
+/// The methods that allocate need the `alloc` feature, and are only available when `T: From<String>`.
+/// There is also an implementation for [str], with `T = str`, which only has the methods that
+/// don't allocate, so anything that derefs to [str] can use those with or without `alloc`.
+pub trait CheckKeyword<T: ?Sized>: AsRef<str> {
+    /// Checks if `self` is a keyword in the default edition.
+    ///
+    /// Raw identifiers like `r#match` aren't keywords, since they're already escaped.
+    fn is_keyword(&self) -> bool {
+        self.is_keyword_in(Edition::default())
+    }
+
+    /// Checks if `self` is a keyword in the given edition.
+    fn is_keyword_in(&self, edition: Edition) -> bool {
+        matches!(self.keyword_kind_in(edition), Some(KeywordKind::Strict | KeywordKind::Reserved))
+    }
+
+    /// Checks if `self` is a weak keyword in the default edition.
+    fn is_weak_keyword(&self) -> bool {
+        self.is_weak_keyword_in(Edition::default())
+    }
 
-    /// If its a keyword, add "r#" to the beginning.
+    /// Checks if `self` is a weak keyword in the given edition.
+    fn is_weak_keyword_in(&self, edition: Edition) -> bool {
+        self.keyword_kind_in(edition) == Some(KeywordKind::Weak)
+    }
+
+    /// The kind of keyword `self` is in the default edition, if any.
+    fn keyword_kind(&self) -> Option<KeywordKind> {
+        self.keyword_kind_in(Edition::default())
+    }
+
+    /// The kind of keyword `self` is in the given edition, if any.
+    fn keyword_kind_in(&self, edition: Edition) -> Option<KeywordKind> {
+        keyword_kind_in(self.as_ref(), edition)
+    }
+
+    /// If its a keyword in the default edition, add "r#" to the beginning,
+    /// or "_" to the end if it can't be a raw identifier.
+    ///
+    /// Names that are already raw are escaped as if they weren't, so `r#match` stays the same,
+    /// but the unnecessary "r#" is removed from `r#foo`, and the invalid `r#self` becomes `self_`.
+    ///
+    /// Repeated "r#" prefixes are all removed, but a lone `r#` is left as it is, so the result is
+    /// never empty. The rest isn't checked, so use [CheckKeyword::try_into_safe] to reject names
+    /// like those instead.
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, IdentError};
+    ///
+    /// assert_eq!("r#r#match".into_safe(), "r#match");
+    /// assert_eq!("r#r#foo".into_safe(), "foo");
+    /// assert_eq!("r#".into_safe(), "r#");
+    /// assert_eq!("r#r#match".try_into_safe(), Err(IdentError::InvalidContinue('#')));
+    /// assert_eq!("r#".try_into_safe(), Err(IdentError::Empty));
+    /// ```
     /// 
     /// This function consumes self, so that if it is not a keyword,
     /// it can return quickly without cloning. If you want to keep ownership
     /// of the original data, clone it first.
-    fn into_safe(self) -> T;
-}
-
-arr!(static KEYWORDS: [&'static str; _] = [
-
-    // STRICT, 2015
-
-    "as",
-    "break",
-    "const",
-    "continue",
-    "crate",
-    "else",
-    "enum",
-    "extern",
-    "false",
-    "fn",
-    "for",
-    "if",
-    "impl",
-    "in",
-    "let",
-    "loop",
-    "match",
-    "mod",
-    "move",
-    "mut",
-    "pub",
-    "ref",
-    "return",
-    "self",
-    "Self",
-    "static",
-    "struct",
-    "super",
-    "trait",
-    "true",
-    "type",
-    "unsafe",
-    "use",
-    "where",
-    "while",
-
-    // STRICT, 2018
-
-    #[cfg(feature = "2018")] "async",
-    #[cfg(feature = "2018")] "await",
-    #[cfg(feature = "2018")] "dyn",
-
-    // RESERVED, 2015
-
-    "abstract",
-    "become",
-    "box",
-    "do",
-    "final",
-    "macro",
-    "override",
-    "priv",
-    "typeof",
-    "unsized",
-    "virtual",
-    "yield",
-
-    // RESERVED, 2018
-
-    #[cfg(feature = "2018")] "try"
-
-]);
+    #[cfg(feature = "alloc")]
+    fn into_safe(self) -> T where Self: Sized, T: From<String> {
+        self.into_safe_for(Edition::default())
+    }
+
+    /// If its a keyword in the given edition, add "r#" to the beginning,
+    /// or "_" to the end if it can't be a raw identifier.
+    ///
+    /// See [CheckKeyword::into_safe].
+    #[cfg(feature = "alloc")]
+    fn into_safe_for(self, edition: Edition) -> T where Self: Sized, T: From<String> {
+        into_safe_cow_for(self.as_ref(), edition).into_owned().into()
+    }
+
+    /// Like [CheckKeyword::into_safe], but escapes keywords with the given strategy instead of "r#".
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, EscapeStrategy};
+    ///
+    /// assert_eq!("type".into_safe_with(EscapeStrategy::TrailingUnderscore), "type_");
+    /// assert_eq!("hello".into_safe_with(EscapeStrategy::TrailingUnderscore), "hello");
+    /// ```
+    ///
+    /// Unlike the default [EscapeStrategy::RawPrefix], the other strategies can't always be undone
+    /// with [CheckKeyword::original_name].
+    #[cfg(feature = "alloc")]
+    fn into_safe_with(self, strategy: EscapeStrategy<'_>) -> T where Self: Sized, T: From<String> {
+        self.into_safe_with_for(strategy, Edition::default())
+    }
+
+    /// Like [CheckKeyword::into_safe_with], but for the given edition.
+    #[cfg(feature = "alloc")]
+    fn into_safe_with_for(self, strategy: EscapeStrategy<'_>, edition: Edition) -> T where Self: Sized, T: From<String> {
+        strategy::into_safe_with(self.as_ref(), strategy, edition).into_owned().into()
+    }
+
+    /// Checks if `self` is in the given set of reserved words.
+    fn is_keyword_in_set<S: KeywordSet + ?Sized>(&self, set: &S) -> bool {
+        set.is_reserved(self.as_ref())
+    }
+
+    /// If `self` is in the given set of reserved words, escape it with the set's escape strategy.
+    ///
+    /// Any "r#" at the beginning is removed first, since it's only valid in Rust. With the
+    /// default [EscapeStrategy::RawPrefix], the set is treated as including the Rust keywords too,
+    /// so the result is always a Rust identifier.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!("inner".into_safe_in_set(&["inner"]), "r#inner");
+    /// assert_eq!("match".into_safe_in_set(&["inner"]), "r#match");
+    /// assert_eq!("self".into_safe_in_set(&["inner"]), "self_");
+    /// ```
+    ///
+    /// See [KeywordSet].
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set<S: KeywordSet + ?Sized>(self, set: &S) -> T where Self: Sized, T: From<String> {
+        set::into_safe_in_set(self.as_ref(), set).into_owned().into()
+    }
+
+    /// Returns the indices of the given sets that `self` is reserved in.
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, RustKeywords};
+    ///
+    /// let targets = [&RustKeywords::EDITION_2021 as _, &["type", "from"] as _];
+    ///
+    /// assert!("from".conflicts_in(&targets).eq([1]));
+    /// assert!("type".conflicts_in(&targets).eq([0, 1]));
+    /// assert_eq!("name".conflicts_in(&targets).next(), None);
+    /// ```
+    fn conflicts_in<'a>(&'a self, sets: &'a [&'a dyn KeywordSet]) -> Conflicts<'a> {
+        Conflicts::new(self.as_ref(), sets)
+    }
+
+    /// Makes a name that's an identifier in most languages, then adds a suffix to it until it
+    /// isn't reserved in any of the given sets, so the same name can be used in several
+    /// languages at once. The suffix is `_`, then `_2`, `_3`, and so on.
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, RustKeywords};
+    ///
+    /// let targets = [&RustKeywords::EDITION_2021 as _, &["type", "type_"] as _];
+    ///
+    /// assert_eq!("type".into_safe_in_all(&targets).unwrap(), "type_2");
+    /// assert_eq!("r#match".into_safe_in_all(&targets).unwrap(), "match_");
+    /// assert_eq!("my-name".into_safe_in_all(&targets).unwrap(), "my_name");
+    /// assert_eq!("naïve".into_safe_in_all(&targets).unwrap(), "naïve");
+    /// ```
+    ///
+    /// Characters that can't be in an identifier are replaced or dropped like
+    /// [CheckKeyword::into_ident], and a raw "r#" prefix is removed. The sets' own escape
+    /// strategies are ignored, since they usually aren't valid in the other languages.
+    ///
+    /// Some sets also reject names for their form rather than for being a word, so each set can
+    /// rewrite the name first with [KeywordSet::normalize]. The SQL dialects keep only ASCII
+    /// letters, digits, and `_`, and lowercase the name if they fold case. C and C++ collapse the
+    /// underscores of reserved identifiers like `__init`, and add `x` to ones like `_Foo`.
+    ///
+    /// Returns an error if a set still reserves the name after a few suffixes, which can only
+    /// happen with a set that reserves names its [KeywordSet::normalize] doesn't fix.
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all(self, sets: &[&dyn KeywordSet]) -> Result<T, ConflictError> where Self: Sized, T: From<String> {
+        Ok(set::into_safe_in_all(self.as_ref(), sets)?.into_owned().into())
+    }
+
+    /// Like [CheckKeyword::into_safe], but checks that the result is a valid identifier.
+    ///
+    /// Returns an error if `self` isn't an identifier or keyword, or if it's a keyword that
+    /// can't be a raw identifier (since adding "_" would change the name).
+    ///
+    /// For the same reason, names that are already identifiers are never renamed, so unlike
+    /// [CheckKeyword::into_safe], `self_` stays `self_` instead of becoming `self__`. This means
+    /// [CheckKeyword::original_name] doesn't undo this: it turns that `self_` into `self`. Keep
+    /// the original name around, or use [CheckKeyword::into_safe], if you need it back.
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, IdentError};
+    ///
+    /// assert_eq!("match".try_into_safe().unwrap(), "r#match");
+    /// assert_eq!("self_".try_into_safe().unwrap(), "self_");
+    /// assert_eq!("self".try_into_safe(), Err(IdentError::NonRawKeyword));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_into_safe(self) -> Result<T, IdentError> where Self: Sized, T: From<String> {
+        self.try_into_safe_for(Edition::default())
+    }
+
+    /// Like [CheckKeyword::into_safe_for], but checks that the result is a valid identifier.
+    ///
+    /// See [CheckKeyword::try_into_safe].
+    #[cfg(feature = "alloc")]
+    fn try_into_safe_for(self, edition: Edition) -> Result<T, IdentError> where Self: Sized, T: From<String> {
+        Ok(ident::check_escapable(self.as_ref(), edition)?.to_string().into())
+    }
+
+    /// Checks if `self` can be used as an identifier in the default edition.
+    ///
+    /// That means it's either a raw identifier (see [CheckKeyword::is_valid_raw_ident]), or it
+    /// follows the identifier rules in the reference and isn't a keyword:
+    /// <https://doc.rust-lang.org/reference/identifiers.html>
+    fn is_valid_ident(&self) -> bool {
+        self.is_valid_ident_in(Edition::default())
+    }
+
+    /// Checks if `self` can be used as an identifier in the given edition.
+    ///
+    /// See [CheckKeyword::is_valid_ident].
+    fn is_valid_ident_in(&self, edition: Edition) -> bool {
+        ident::is_valid_ident_in(self.as_ref(), edition)
+    }
+
+    /// Checks if `self` is a valid raw identifier, like `r#match`.
+    ///
+    /// This doesn't depend on the edition, since any identifier or keyword can be made raw,
+    /// except for `self`, `Self`, `super`, `crate`, and `_`.
+    fn is_valid_raw_ident(&self) -> bool {
+        ident::is_valid_raw_ident(self.as_ref())
+    }
+
+    /// Turns any string into a valid identifier, replacing or dropping invalid characters,
+    /// then escaping keywords like [CheckKeyword::into_safe].
+    ///
+    /// ASCII characters that can't be in an identifier are replaced with `_`, and other invalid
+    /// characters are dropped. If it can't start with the first character (like a digit), it is
+    /// prefixed with `_`.
+    #[cfg(feature = "alloc")]
+    fn into_ident(self) -> T where Self: Sized, T: From<String> {
+        self.into_ident_with(&SanitizeOptions::default())
+    }
+
+    /// Like [CheckKeyword::into_ident], but with configurable options.
+    #[cfg(feature = "alloc")]
+    fn into_ident_with(self, options: &SanitizeOptions<'_>) -> T where Self: Sized, T: From<String> {
+        sanitize::sanitize(self.as_ref(), options).into()
+    }
+
+    /// Encodes any string as a valid identifier that isn't a keyword in any edition, in a way
+    /// that can be undone with [CheckKeyword::decode_ident].
+    ///
+    /// ASCII letters and digits are kept, and `_` is doubled. Any other character, along with a
+    /// leading digit or the first letter of a keyword, becomes `_`, its code point in hex, and `_`.
+    /// The empty string becomes `_empty`.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!("a-b".encode_ident(), "a_2d_b");
+    /// assert_eq!("a.b".encode_ident(), "a_2e_b");
+    /// assert_eq!("a_b".encode_ident(), "a__b");
+    /// assert_eq!("type".encode_ident(), "_74_ype");
+    /// ```
+    ///
+    /// Unlike [CheckKeyword::into_ident], different strings are always encoded differently.
+    #[cfg(feature = "alloc")]
+    fn encode_ident(self) -> T where Self: Sized, T: From<String> {
+        encode::encode(self.as_ref()).into_owned().into()
+    }
+
+    /// Decodes an identifier made by [CheckKeyword::encode_ident].
+    ///
+    /// Returns an error if `self` isn't exactly what [CheckKeyword::encode_ident] would make
+    /// for some string.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!("a_2d_b".decode_ident().unwrap(), "a-b");
+    /// assert!("a-b".decode_ident().is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn decode_ident(self) -> Result<T, DecodeIdentError> where Self: Sized, T: From<String> {
+        Ok(encode::decode(self.as_ref())?.into_owned().into())
+    }
+
+    /// Borrows `self` as something that writes the same thing as [CheckKeyword::into_safe]
+    /// when formatted, without allocating.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!(format!("let {} = 1;", "match".safe_display()), "let r#match = 1;");
+    /// ```
+    fn safe_display(&self) -> SafeDisplay<'_> {
+        self.safe_display_in(Edition::default())
+    }
+
+    /// Like [CheckKeyword::safe_display], but for the given edition.
+    fn safe_display_in(&self, edition: Edition) -> SafeDisplay<'_> {
+        safe_display_in(self.as_ref(), edition)
+    }
+
+    /// Writes the same thing as [CheckKeyword::into_safe] into `buffer`, for when there's no allocator.
+    ///
+    /// Returns the written part of the buffer, or `None` if it didn't fit.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// let mut buffer = [0; 16];
+    /// assert_eq!("match".write_safe(&mut buffer), Some("r#match"));
+    /// ```
+    fn write_safe<'b>(&self, buffer: &'b mut [u8]) -> Option<&'b str> {
+        self.write_safe_for(buffer, Edition::default())
+    }
+
+    /// Like [CheckKeyword::write_safe], but for the given edition.
+    fn write_safe_for<'b>(&self, buffer: &'b mut [u8], edition: Edition) -> Option<&'b str> {
+        self.safe_display_in(edition).write_to(buffer)
+    }
+
+    /// Checks if `self` starts with "r#".
+    fn is_raw(&self) -> bool {
+        self.as_ref().starts_with("r#")
+    }
+
+    /// Borrows `self` without the "r#" at the beginning, if there is one.
+    ///
+    /// Repeated "r#" prefixes are all removed, except that a lone `r#` is left as it is. What's
+    /// left isn't checked to be an identifier.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!("r#match".unraw(), "match");
+    /// assert_eq!("match".unraw(), "match");
+    /// assert_eq!("r#r#match".unraw(), "match");
+    /// assert_eq!("r#".unraw(), "r#");
+    /// ```
+    fn unraw(&self) -> &str {
+        unraw(self.as_ref())
+    }
+
+    /// Undoes the escape applied by [CheckKeyword::into_safe], or any other edition's
+    /// [CheckKeyword::into_safe_for].
+    ///
+    /// For any `name` that doesn't already start with "r#", `name.into_safe().original_name() == name`.
+    ///
+    /// This doesn't undo [CheckKeyword::try_into_safe], which leaves names like `self_` as they
+    /// are, so `"self_".try_into_safe()` gives `self_`, but its original name is `self`.
+    ///
+    /// ```
+    /// use check_keyword::CheckKeyword;
+    ///
+    /// assert_eq!("r#match".original_name(), "match");
+    /// assert_eq!("self_".original_name(), "self");
+    /// assert_eq!("self__".original_name(), "self_");
+    /// assert_eq!("hello".original_name(), "hello");
+    /// ```
+    fn original_name(&self) -> &str {
+        original_name(self.as_ref())
+    }
+
+    /// Which escape [CheckKeyword::into_safe] applies to `self` in the default edition.
+    ///
+    /// If `self` is already raw, this is the escape applied to [CheckKeyword::unraw], which
+    /// may be different from the one `self` has.
+    fn escape(&self) -> Escape {
+        self.escape_in(Edition::default())
+    }
+
+    /// Which escape [CheckKeyword::into_safe_for] applies to `self` in the given edition.
+    fn escape_in(&self, edition: Edition) -> Escape {
+        escape_in(self.as_ref(), edition)
+    }
+}
+
+/// The escape applied by [CheckKeyword::into_safe].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Escape {
+    /// Not a keyword, returned as-is (without any unnecessary "r#").
+    Unchanged,
+    /// A keyword, prefixed with "r#".
+    Raw,
+    /// A keyword that can't be a raw identifier, suffixed with "_".
+    ///
+    /// Those keywords followed by underscores, like `self_`, also get another underscore,
+    /// so that [CheckKeyword::original_name] can tell them apart.
+    TrailingUnderscore,
+}
+
+/// The kinds of keywords, as described in the reference:
+/// <https://doc.rust-lang.org/reference/keywords.html>
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeywordKind {
+    /// Can only be used in its correct context, like `match`.
+    Strict,
+    /// Not used yet, but reserved for future use, like `abstract`.
+    Reserved,
+    /// Only has special meaning in certain contexts, and can otherwise be used as an identifier, like `union`.
+    Weak,
+}
+
+/// A Rust edition, which determines the set of keywords that are checked against.
+///
+/// Editions are ordered, and each edition includes the keywords of the editions before it.
+/// Rust 2021 did not add any keywords, so it checks the same set as Rust 2018.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Edition {
+    Edition2015,
+    Edition2018,
+    Edition2021,
+    Edition2024,
+}
+
+impl Edition {
+    /// The same as [Edition::default], for const contexts.
+    pub const DEFAULT: Edition = if cfg!(feature = "2018") {
+        Edition::Edition2018
+    } else {
+        Edition::Edition2015
+    };
+}
+
+impl Default for Edition {
+    /// Rust 2018 if the `2018` feature is enabled (it is by default), otherwise Rust 2015.
+    fn default() -> Self {
+        Edition::DEFAULT
+    }
+}
+
+/// Checks if `name` is a keyword in the default edition, in a const context.
+///
+/// ```
+/// use check_keyword::is_keyword_const;
+///
+/// const IS_KEYWORD: bool = is_keyword_const("match");
+/// assert!(IS_KEYWORD);
+/// ```
+///
+/// See also [assert_not_keyword].
+pub const fn is_keyword_const(name: &str) -> bool {
+    is_keyword_in(name, Edition::DEFAULT)
+}
+
+/// Checks if `name` is a keyword in the given edition, in a const context.
+///
+/// See [is_keyword_const].
+pub const fn is_keyword_const_in(name: &str, edition: Edition) -> bool {
+    is_keyword_in(name, edition)
+}
+
+/// Fails to compile if any of the given string literals are keywords.
+///
+/// Checks against the default edition, unless an edition is given first.
+///
+/// ```
+/// use check_keyword::{assert_not_keyword, Edition};
+///
+/// assert_not_keyword!("foo", "bar");
+/// assert_not_keyword!(Edition::Edition2021; "gen");
+/// ```
+///
+/// ```compile_fail
+/// check_keyword::assert_not_keyword!("foo", "match");
+/// ```
+///
+/// ```compile_fail
+/// check_keyword::assert_not_keyword!(check_keyword::Edition::Edition2024; "gen");
+/// ```
+#[macro_export]
+macro_rules! assert_not_keyword {
+    ($($name:literal),+ $(,)?) => {
+        $crate::assert_not_keyword!($crate::Edition::DEFAULT; $($name),+);
+    };
+    ($edition:expr; $($name:literal),+ $(,)?) => {
+        const _: () = {
+            $(assert!(!$crate::is_keyword_const_in($name, $edition), concat!("`", $name, "` is a keyword"));)+
+        };
+    };
+}
+
+/// Like [CheckKeyword::into_safe], but only allocates if `name` needs to be escaped.
+///
+/// ```
+/// use std::borrow::Cow;
+/// use check_keyword::into_safe_cow;
+///
+/// assert!(matches!(into_safe_cow("hello"), Cow::Borrowed("hello")));
+/// assert_eq!(into_safe_cow("match"), "r#match");
+/// ```
+///
+/// The blanket implementation of [CheckKeyword] also covers `Cow<str>`, so `Cow::from(name).into_safe()`
+/// does the same thing.
+#[cfg(feature = "alloc")]
+pub fn into_safe_cow(name: &str) -> Cow<'_, str> {
+    into_safe_cow_for(name, Edition::default())
+}
+
+/// Like [CheckKeyword::into_safe_for], but only allocates if `name` needs to be escaped.
+///
+/// See [into_safe_cow].
+#[cfg(feature = "alloc")]
+pub fn into_safe_cow_for(name: &str, edition: Edition) -> Cow<'_, str> {
+    let safe = safe_display_in(name, edition);
+    if safe.is_same_as(name) {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(safe.to_string())
+    }
+}
+
+/// Checks what kind of keyword `name` is in `edition`.
+const fn keyword_kind_in(name: &str, edition: Edition) -> Option<KeywordKind> {
+    match Keyword::lookup(name) {
+        Some(keyword) => keyword.kind_in(edition),
+        None => None
+    }
+}
+
+/// Checks if `name` is a strict or reserved keyword in `edition`.
+const fn is_keyword_in(name: &str, edition: Edition) -> bool {
+    matches!(keyword_kind_in(name, edition), Some(KeywordKind::Strict | KeywordKind::Reserved))
+}
+
+/// Removes every "r#" from the beginning of `name`, but leaves a lone "r#" so it isn't empty.
+fn unraw(mut name: &str) -> &str {
+    while let Some(rest) = name.strip_prefix("r#").filter(|rest| !rest.is_empty()) {
+        name = rest;
+    }
+    name
+}
+
+/// Escapes `name` for `edition`, ignoring any "r#" it already has.
+fn safe_display_in(name: &str, edition: Edition) -> SafeDisplay<'_> {
+    let name = unraw(name);
+    SafeDisplay::new(name, escape_in(name, edition))
+}
+
+/// Checks which escape `name` needs in `edition`, ignoring any "r#" it already has.
+fn escape_in(name: &str, edition: Edition) -> Escape {
+    let name = unraw(name);
+    if is_keyword_in(name, edition) && !NON_RAW_KEYWORDS.contains(&name) {
+        Escape::Raw
+    } else if is_non_raw_keyword_with_underscores(name) {
+        Escape::TrailingUnderscore
+    } else {
+        Escape::Unchanged
+    }
+}
+
+/// Checks if `name` is a non-raw keyword followed by any number of underscores, like `self__`.
+///
+/// These all get another underscore, so that the escape can be undone.
+fn is_non_raw_keyword_with_underscores(name: &str) -> bool {
+    let trimmed = name.trim_end_matches('_');
+    if trimmed.is_empty() {
+        !name.is_empty()
+    } else {
+        NON_RAW_KEYWORDS.contains(&trimmed)
+    }
+}
+
+/// Undoes the escape applied by [CheckKeyword::into_safe].
+fn original_name(name: &str) -> &str {
+    match name.strip_prefix("r#") {
+        Some(unraw) => unraw,
+        None if name.ends_with('_') && is_non_raw_keyword_with_underscores(&name[..name.len() - 1]) => {
+            &name[..name.len() - 1]
+        }
+        None => name
+    }
+}
+
+// Keywords that can't be used as raw identifiers.
+arr!(static NON_RAW_KEYWORDS: [&'static str; _] = ["self", "Self", "super", "crate", "_"]);
 
 #[cfg(test)]
 mod tests {
@@ -139,6 +857,32 @@ mod tests {
 
     #[test]
     fn array_length() {
-        println!("Number of keywords: {}", KEYWORDS.len());
+        println!("Number of keywords: {}", Keyword::ALL.len());
+    }
+
+    assert_not_keyword!("foo", "union", "r#match");
+    assert_not_keyword!(Edition::Edition2015; "async", "dyn");
+
+    #[test]
+    fn is_keyword_const() {
+        const {
+            assert!(super::is_keyword_const("match"));
+            assert!(is_keyword_const_in("gen", Edition::Edition2024));
+        }
+        assert!(!super::is_keyword_const("hello"));
+        assert_eq!(super::is_keyword_const("async"), cfg!(feature = "2018"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_cow() {
+        assert!(matches!(super::into_safe_cow("hello"), Cow::Borrowed("hello")));
+        assert!(matches!(super::into_safe_cow("match"), Cow::Owned(_)));
+        assert_eq!(super::into_safe_cow("self"), "self_");
+        assert_eq!(into_safe_cow_for("gen", Edition::Edition2024), "r#gen");
+        assert_eq!(Cow::from("match").into_safe(), "r#match");
+
+        assert!(matches!(super::into_safe_cow("r#match"), Cow::Borrowed("r#match")));
+        assert_eq!(super::into_safe_cow("r#foo"), "foo");
     }
 }
\ No newline at end of file
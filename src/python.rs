@@ -0,0 +1,120 @@
+//! Python 3 keywords, plus the soft keywords like `match` that are only reserved in some
+//! statements, and the builtins like `id` that a name would shadow.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::python::Python;
+//!
+//! assert!("class".is_keyword_in_set(&Python::HARD));
+//! assert_eq!("class".into_safe_in_set(&Python::HARD), "class_");
+//! assert_eq!("match".into_safe_in_set(&Python::SOFT), "match_");
+//! assert_eq!("id".into_safe_in_set(&Python::ALL), "id_");
+//! ```
+
+use super::*;
+
+/// Python's hard keywords, which can never be used as names.
+pub const HARD_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+    "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// Python's soft keywords, which are only keywords in certain contexts, like `match` statements.
+pub const SOFT_KEYWORDS: &[&str] = &["_", "case", "match", "type"];
+
+/// The functions, types, and constants in Python's `builtins` module, which can be used as names
+/// but shadow the builtin. Exceptions and dunder names aren't included.
+pub const BUILTINS: &[&str] = &[
+    "Ellipsis", "NotImplemented", "abs", "aiter", "all", "anext", "any", "ascii", "bin", "bool",
+    "breakpoint", "bytearray", "bytes", "callable", "chr", "classmethod", "compile", "complex",
+    "copyright", "credits", "delattr", "dict", "dir", "divmod", "enumerate", "eval", "exec", "exit",
+    "filter", "float", "format", "frozenset", "getattr", "globals", "hasattr", "hash", "help", "hex",
+    "id", "input", "int", "isinstance", "issubclass", "iter", "len", "license", "list", "locals",
+    "map", "max", "memoryview", "min", "next", "object", "oct", "open", "ord", "pow", "print",
+    "property", "quit", "range", "repr", "reversed", "round", "set", "setattr", "slice", "sorted",
+    "staticmethod", "str", "sum", "super", "tuple", "type", "vars", "zip",
+];
+
+/// Checks if `name` is a hard keyword.
+pub fn is_keyword(name: &str) -> bool {
+    HARD_KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` is a soft keyword.
+pub fn is_soft_keyword(name: &str) -> bool {
+    SOFT_KEYWORDS.contains(&name)
+}
+
+/// Checks if `name` would shadow a builtin.
+pub fn is_builtin(name: &str) -> bool {
+    BUILTINS.contains(&name)
+}
+
+/// A [KeywordSet] of Python names, escaped with a trailing underscore as recommended by PEP 8.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Python {
+    /// Also escape soft keywords.
+    pub soft_keywords: bool,
+    /// Also escape names that would shadow a builtin.
+    pub builtins: bool,
+}
+
+impl Python {
+    /// Only hard keywords.
+    pub const HARD: Python = Python { soft_keywords: false, builtins: false };
+    /// Hard and soft keywords.
+    pub const SOFT: Python = Python { soft_keywords: true, builtins: false };
+    /// Hard and soft keywords, and builtins.
+    pub const ALL: Python = Python { soft_keywords: true, builtins: true };
+}
+
+impl KeywordSet for Python {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_keyword(name)
+            || (self.soft_keywords && is_soft_keyword(name))
+            || (self.builtins && is_builtin(name))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::TrailingUnderscore
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_keyword("class"));
+        assert!(is_keyword("None"));
+        assert!(!is_keyword("match"));
+        assert!(is_soft_keyword("match"));
+        assert!(is_soft_keyword("_"));
+        assert!(is_builtin("id"));
+        assert!(!is_builtin("class"));
+    }
+
+    #[test]
+    fn is_keyword_in_set() {
+        assert!("lambda".is_keyword_in_set(&Python::HARD));
+        assert!(!"case".is_keyword_in_set(&Python::HARD));
+        assert!("case".is_keyword_in_set(&Python::SOFT));
+        assert!(!"list".is_keyword_in_set(&Python::SOFT));
+        assert!("list".is_keyword_in_set(&Python::ALL));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("class".into_safe_in_set(&Python::HARD), "class_");
+        assert_eq!(String::from("from").into_safe_in_set(&Python::HARD), "from_");
+        assert_eq!("type".into_safe_in_set(&Python::HARD), "type");
+        assert_eq!("type".into_safe_in_set(&Python::SOFT), "type_");
+        assert_eq!("hello".into_safe_in_set(&Python::ALL), "hello");
+        assert_eq!("r#match".into_safe_in_set(&Python::SOFT), "match_");
+        assert_eq!("r#class".into_safe_in_set(&Python::HARD), "class_");
+    }
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+use alloc::string::String;
+
+/// Options for [CheckKeyword::into_ident_with].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SanitizeOptions<'a> {
+    /// Replaces ASCII characters that can't be in an identifier, like `-`, ` `, and `.`.
+    /// Defaults to `_`, which is also used if this can't be in an identifier.
+    pub replacement: char,
+    /// Added to the beginning if the name doesn't start with a character that can start an
+    /// identifier, like a digit. Defaults to `_`, which is also used if this can't start an
+    /// identifier by itself.
+    pub digit_prefix: &'a str,
+    /// The edition to escape keywords for. Defaults to [Edition::default].
+    pub edition: Edition,
+}
+
+impl Default for SanitizeOptions<'_> {
+    fn default() -> Self {
+        SanitizeOptions {
+            replacement: '_',
+            digit_prefix: "_",
+            edition: Edition::default(),
+        }
+    }
+}
+
+/// Turns `name` into a valid identifier.
+///
+/// Non-ASCII characters that can't be in an identifier (like emoji) are dropped, since
+/// replacing them usually isn't what you want. Raw identifiers are kept as they are, if possible.
+pub(crate) fn sanitize(name: &str, options: &SanitizeOptions<'_>) -> String {
+    let replacement = match options.replacement {
+        c if unicode_ident::is_xid_continue(c) => c,
+        _ => '_'
+    };
+    let digit_prefix = match ident::check_ident_or_keyword(options.digit_prefix) {
+        Ok(()) => options.digit_prefix,
+        Err(_) => "_"
+    };
+
+    replace_invalid(unraw(name), replacement, digit_prefix).into_safe_for(options.edition)
+}
+
+/// Replaces the ASCII characters in `name` that can't be in an identifier with `replacement`,
+/// drops the others, and adds `digit_prefix` if the result can't start an identifier. Keywords
+/// aren't escaped.
+pub(crate) fn replace_invalid(name: &str, replacement: char, digit_prefix: &str) -> String {
+    let mut ident = String::with_capacity(name.len());
+    for c in name.chars() {
+        if unicode_ident::is_xid_continue(c) {
+            ident.push(c);
+        } else if c.is_ascii() {
+            ident.push(replacement);
+        }
+    }
+    if !ident.starts_with(|c| c == '_' || unicode_ident::is_xid_start(c)) {
+        ident.insert_str(0, digit_prefix);
+    }
+    ident
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_ident() {
+        assert_eq!("foo".into_ident(), "foo");
+        assert_eq!("foo-bar baz.qux".into_ident(), "foo_bar_baz_qux");
+        assert_eq!("2fast".into_ident(), "_2fast");
+        assert_eq!("naïve".into_ident(), "naïve");
+        assert_eq!("party🎉time".into_ident(), "partytime");
+        assert_eq!(String::from("match").into_ident(), "r#match");
+        assert_eq!("self".into_ident(), "self_");
+        assert_eq!("🎉".into_ident(), "__");
+        assert_eq!("".into_ident(), "__");
+        assert_eq!("r#match".into_ident(), "r#match");
+        assert_eq!("r#foo".into_ident(), "foo");
+    }
+
+    #[test]
+    fn into_ident_with() {
+        let options = SanitizeOptions {
+            replacement: 'x',
+            digit_prefix: "n",
+            edition: Edition::Edition2024,
+        };
+
+        assert_eq!("a-b".into_ident_with(&options), "axb");
+        assert_eq!("1-2".into_ident_with(&options), "n1x2");
+        assert_eq!(String::from("gen").into_ident_with(&options), "r#gen");
+
+        let digit_prefix = String::from("num");
+        let options = SanitizeOptions { digit_prefix: &digit_prefix, ..options };
+        assert_eq!("1-2".into_ident_with(&options), "num1x2");
+    }
+
+    #[test]
+    fn invalid_options() {
+        let options = SanitizeOptions {
+            replacement: '-',
+            digit_prefix: "1",
+            edition: Edition::Edition2024,
+        };
+
+        assert_eq!("a-b".into_ident_with(&options), "a_b");
+        assert_eq!("1".into_ident_with(&options), "_1");
+
+        let options = SanitizeOptions { digit_prefix: "", ..options };
+        assert_eq!("1".into_ident_with(&options), "_1");
+    }
+}
@@ -0,0 +1,154 @@
+use super::*;
+
+use alloc::{collections::BTreeSet, format, string::String};
+
+/// Hands out escaped names that don't collide with any name it has already handed out.
+///
+/// Escaping is stateless, so different inputs can end up with the same name, like `"type"` and
+/// `"type_"` with [EscapeStrategy::TrailingUnderscore]. A scope remembers the names it has issued,
+/// and adds `_1`, `_2`, and so on to any name that's already taken.
+///
+/// ```
+/// use check_keyword::{CheckKeyword, NameScope};
+///
+/// let mut scope = NameScope::new();
+///
+/// assert_eq!(scope.allocate("foo"), "foo");
+/// assert_eq!(scope.allocate("foo"), "foo_1");
+/// assert_eq!(scope.allocate("foo"), "foo_2");
+/// assert_eq!(scope.allocate("type"), "r#type");
+/// assert_eq!(scope.allocate(&"Foo-Bar".into_ident()), "Foo_Bar");
+/// assert_eq!(scope.allocate(&"Foo_Bar".into_ident()), "Foo_Bar_1");
+/// ```
+///
+/// Nested scopes, like for a module or an impl block, are made with [NameScope::child]. A child
+/// avoids the names of its ancestors so it never shadows them, but its own names are forgotten
+/// once it's dropped.
+///
+/// ```
+/// use check_keyword::NameScope;
+///
+/// let mut module = NameScope::new();
+/// assert_eq!(module.allocate("new"), "new");
+///
+/// let mut inner = module.child();
+/// assert_eq!(inner.allocate("new"), "new_1");
+/// assert_eq!(inner.allocate("other"), "other");
+/// drop(inner);
+///
+/// assert_eq!(module.allocate("other"), "other");
+/// ```
+pub struct NameScope<'a> {
+    parent: Option<&'a NameScope<'a>>,
+    set: &'a dyn KeywordSet,
+    names: BTreeSet<String>,
+}
+
+impl NameScope<'static> {
+    /// A scope that escapes Rust keywords in the default edition, like [CheckKeyword::into_safe].
+    pub fn new() -> Self {
+        NameScope::with_set(&RustKeywords { edition: Edition::DEFAULT })
+    }
+}
+
+impl Default for NameScope<'static> {
+    fn default() -> Self {
+        NameScope::new()
+    }
+}
+
+impl<'a> NameScope<'a> {
+    /// A scope that escapes names in `set`, like [CheckKeyword::into_safe_in_set].
+    pub fn with_set(set: &'a dyn KeywordSet) -> Self {
+        NameScope { parent: None, set, names: BTreeSet::new() }
+    }
+
+    /// A nested scope with the same keyword set, which avoids the names in this one.
+    pub fn child(&self) -> NameScope<'_> {
+        NameScope { parent: Some(self), set: self.set, names: BTreeSet::new() }
+    }
+
+    /// Checks if `name` has been issued by this scope or one of its ancestors.
+    ///
+    /// Raw identifiers are the same as their unraw name, so `r#type` is taken if `type` is.
+    pub fn is_taken(&self, name: &str) -> bool {
+        let name = unraw(name);
+        self.names.contains(name) || self.parent.is_some_and(|parent| parent.is_taken(name))
+    }
+
+    /// Marks `name` as taken without escaping it, like for a name that comes from elsewhere.
+    ///
+    /// Returns false if it was already taken.
+    pub fn reserve(&mut self, name: &str) -> bool {
+        !self.is_taken(name) && self.names.insert(String::from(unraw(name)))
+    }
+
+    /// Escapes `name` with the scope's keyword set, and adds `_1`, `_2`, and so on
+    /// until it isn't taken.
+    ///
+    /// The result only depends on the names allocated before it, so the same inputs in the same
+    /// order always get the same names.
+    pub fn allocate(&mut self, name: &str) -> String {
+        let mut safe = set::into_safe_in_set(name, self.set).into_owned();
+        let mut suffix = 1;
+        while !self.reserve(&safe) {
+            safe = set::into_safe_in_set(&format!("{}_{}", unraw(name), suffix), self.set).into_owned();
+            suffix += 1;
+        }
+        safe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate() {
+        let mut scope = NameScope::new();
+        assert_eq!(scope.allocate("foo"), "foo");
+        assert_eq!(scope.allocate("foo"), "foo_1");
+        assert_eq!(scope.allocate("foo_1"), "foo_1_1");
+        assert_eq!(scope.allocate("foo"), "foo_2");
+        assert_eq!(scope.allocate("match"), "r#match");
+        assert_eq!(scope.allocate("r#match"), "match_1");
+        assert_eq!(scope.allocate("self"), "self_");
+        assert_eq!(scope.allocate("self_"), "self__");
+        assert_eq!(scope.allocate("self"), "self_1");
+    }
+
+    #[test]
+    fn trailing_underscore() {
+        let set = RustKeywords::EDITION_2021.escape_with(EscapeStrategy::TrailingUnderscore);
+        let mut scope = NameScope::with_set(&set);
+        assert_eq!(scope.allocate("type"), "type_");
+        assert_eq!(scope.allocate("type_"), "type__1");
+        assert_eq!(scope.allocate("type"), "type_1");
+    }
+
+    #[test]
+    fn reserve() {
+        let mut scope = NameScope::new();
+        assert!(scope.reserve("Self_"));
+        assert!(!scope.reserve("Self_"));
+        assert!(scope.is_taken("r#Self_"));
+        assert_eq!(scope.allocate("Self"), "Self_1");
+    }
+
+    #[test]
+    fn child() {
+        let mut parent = NameScope::new();
+        parent.allocate("a");
+        {
+            let mut child = parent.child();
+            assert_eq!(child.allocate("a"), "a_1");
+            assert_eq!(child.allocate("b"), "b");
+            let mut grandchild = child.child();
+            assert_eq!(grandchild.allocate("a"), "a_2");
+            assert_eq!(grandchild.allocate("b"), "b_1");
+        }
+        assert!(!parent.is_taken("b"));
+        assert_eq!(parent.allocate("b"), "b");
+        assert_eq!(parent.allocate("a"), "a_1");
+    }
+}
@@ -0,0 +1,353 @@
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt::{self, Write};
+
+/// A set of reserved words, like the keywords of a language, for [CheckKeyword::is_keyword_in_set]
+/// and [CheckKeyword::into_safe_in_set].
+///
+/// Each language's set escapes names the way that language can. Languages that can quote a
+/// keyword, like Kotlin and Swift with backticks, keep the name, and the ones that can't, like Go
+/// and Java, add a trailing underscore instead.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use check_keyword::{CheckKeyword, KeywordSet, RustKeywords};
+///
+/// let set = RustKeywords::EDITION_2021.with(["Error", "Result"]);
+///
+/// assert!("Error".is_keyword_in_set(&set));
+/// assert!("match".is_keyword_in_set(&set));
+/// assert_eq!("Result".into_safe_in_set(&set), "r#Result");
+/// # }
+/// ```
+pub trait KeywordSet {
+    /// Checks if `name` is in the set, and needs to be escaped.
+    fn is_reserved(&self, name: &str) -> bool;
+
+    /// How [CheckKeyword::into_safe_in_set] escapes names in the set. Defaults to [EscapeStrategy::RawPrefix].
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::RawPrefix
+    }
+
+    /// Rewrites `name` so the set doesn't reject it for its form, like a SQL dialect that folds
+    /// case, before [CheckKeyword::into_safe_in_all] looks for a name that isn't a reserved word.
+    /// Defaults to leaving it unchanged.
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(name)
+    }
+
+    /// Combines this set with another one. The escape strategy of this set is used.
+    fn with<S: KeywordSet>(self, other: S) -> Union<Self, S> where Self: Sized {
+        Union { first: self, second: other }
+    }
+
+    /// Uses a different escape strategy for this set.
+    #[cfg(feature = "alloc")]
+    fn escape_with(self, strategy: EscapeStrategy<'_>) -> WithStrategy<'_, Self> where Self: Sized {
+        WithStrategy { set: self, strategy }
+    }
+}
+
+/// The strict and reserved Rust keywords of an edition, the same ones checked by [CheckKeyword::is_keyword_in].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct RustKeywords {
+    pub edition: Edition,
+}
+
+impl RustKeywords {
+    pub const EDITION_2015: RustKeywords = RustKeywords { edition: Edition::Edition2015 };
+    pub const EDITION_2018: RustKeywords = RustKeywords { edition: Edition::Edition2018 };
+    pub const EDITION_2021: RustKeywords = RustKeywords { edition: Edition::Edition2021 };
+    pub const EDITION_2024: RustKeywords = RustKeywords { edition: Edition::Edition2024 };
+}
+
+impl KeywordSet for RustKeywords {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_keyword_in(name, self.edition)
+    }
+}
+
+/// Two sets combined, returned by [KeywordSet::with].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Union<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: KeywordSet, B: KeywordSet> KeywordSet for Union<A, B> {
+    fn is_reserved(&self, name: &str) -> bool {
+        self.first.is_reserved(name) || self.second.is_reserved(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        self.first.escape_strategy()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.first.normalize(name) {
+            Cow::Borrowed(name) => self.second.normalize(name),
+            Cow::Owned(name) => Cow::Owned(self.second.normalize(&name).into_owned()),
+        }
+    }
+}
+
+/// A set with a different escape strategy, returned by [KeywordSet::escape_with].
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug)]
+pub struct WithStrategy<'a, S> {
+    set: S,
+    strategy: EscapeStrategy<'a>,
+}
+
+#[cfg(feature = "alloc")]
+impl<S: KeywordSet> KeywordSet for WithStrategy<'_, S> {
+    fn is_reserved(&self, name: &str) -> bool {
+        self.set.is_reserved(name)
+    }
+
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        self.strategy
+    }
+
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        self.set.normalize(name)
+    }
+}
+
+impl<S: KeywordSet + ?Sized> KeywordSet for &S {
+    fn is_reserved(&self, name: &str) -> bool {
+        (**self).is_reserved(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        (**self).escape_strategy()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        (**self).normalize(name)
+    }
+}
+
+impl<S: AsRef<str>> KeywordSet for [S] {
+    fn is_reserved(&self, name: &str) -> bool {
+        self.iter().any(|word| word.as_ref() == name)
+    }
+}
+
+impl<S: AsRef<str>, const N: usize> KeywordSet for [S; N] {
+    fn is_reserved(&self, name: &str) -> bool {
+        self[..].is_reserved(name)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> KeywordSet for Vec<S> {
+    fn is_reserved(&self, name: &str) -> bool {
+        self[..].is_reserved(name)
+    }
+}
+
+/// Escapes `name` if it's in `set`.
+///
+/// Any "r#" is removed first, like [CheckKeyword::into_safe_with], so it doesn't end up in
+/// another language. [EscapeStrategy::RawPrefix] is only for Rust, so with it the set is treated
+/// as including the keywords of the default edition, and names that aren't in the set are
+/// escaped like [CheckKeyword::into_safe].
+#[cfg(feature = "alloc")]
+pub(crate) fn into_safe_in_set<'a, S: KeywordSet + ?Sized>(name: &'a str, set: &S) -> Cow<'a, str> {
+    match set.escape_strategy() {
+        strategy if set.is_reserved(unraw(name)) => Cow::Owned(strategy.apply(unraw(name))),
+        EscapeStrategy::RawPrefix => into_safe_cow_for(name, Edition::default()),
+        _ => Cow::Borrowed(unraw(name))
+    }
+}
+
+/// The indices of the sets that a name is reserved in, returned by [CheckKeyword::conflicts_in].
+#[derive(Clone)]
+pub struct Conflicts<'a> {
+    name: &'a str,
+    sets: core::iter::Enumerate<core::slice::Iter<'a, &'a dyn KeywordSet>>,
+}
+
+impl<'a> Conflicts<'a> {
+    pub(crate) fn new(name: &'a str, sets: &'a [&'a dyn KeywordSet]) -> Self {
+        Conflicts { name, sets: sets.iter().enumerate() }
+    }
+}
+
+impl Iterator for Conflicts<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let name = self.name;
+        self.sets.find(|(_, set)| set.is_reserved(name)).map(|(index, _)| index)
+    }
+}
+
+/// The error returned by [CheckKeyword::into_safe_in_all] if a set reserves every name it tried.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConflictError {
+    /// The index of the set that reserved the last name tried.
+    pub set: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no name found that set {} doesn't reserve", self.set)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ConflictError {}
+
+/// How many suffixes [into_safe_in_all] tries before giving up.
+#[cfg(feature = "alloc")]
+const MAX_ATTEMPTS: usize = 8;
+
+/// Makes `name` an identifier, normalizes it for every set, then adds `_`, `_2`, `_3`, ... until
+/// no set reserves it. Numbers are used after the first `_`, since C++ reserves `__`.
+#[cfg(feature = "alloc")]
+pub(crate) fn into_safe_in_all<'a>(name: &'a str, sets: &[&dyn KeywordSet]) -> Result<Cow<'a, str>, ConflictError> {
+    let conflict = |name: &str| sets.iter().position(|set| set.is_reserved(name));
+    let normalize = |name: Cow<'a, str>| {
+        sets.iter().fold(name, |name, set| match set.normalize(&name) {
+            Cow::Borrowed(_) => None,
+            Cow::Owned(normalized) => Some(normalized),
+        }.map_or(name, Cow::Owned))
+    };
+
+    let name = unraw(name);
+    let base = if ident::check_ident_or_keyword(name).is_ok() {
+        normalize(Cow::Borrowed(name))
+    } else {
+        normalize(Cow::Owned(sanitize::replace_invalid(name, '_', "_")))
+    };
+    let mut set = match conflict(&base) {
+        None => return Ok(base),
+        Some(set) => set
+    };
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        let mut candidate = String::from(&*base);
+        candidate.push('_');
+        if attempt > 1 {
+            let _ = write!(candidate, "{}", attempt);
+        }
+        let candidate = normalize(Cow::Owned(candidate));
+        match conflict(&candidate) {
+            None => return Ok(candidate),
+            Some(conflict) => set = conflict
+        }
+    }
+    Err(ConflictError { set })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_keyword_in_set() {
+        let set = RustKeywords::EDITION_2021.with(["Error", "Result"]);
+        assert!("Error".is_keyword_in_set(&set));
+        assert!(String::from("match").is_keyword_in_set(&set));
+        assert!(!"gen".is_keyword_in_set(&set));
+        assert!(!"hello".is_keyword_in_set(&set));
+
+        assert!("gen".is_keyword_in_set(&RustKeywords::EDITION_2024));
+        assert!("inner".is_keyword_in_set(&["inner", "Client"][..]));
+        assert!(!"match".is_keyword_in_set(&["inner"]));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        let set = RustKeywords::EDITION_2021.with(["Error", "Result"]);
+        assert_eq!("Error".into_safe_in_set(&set), "r#Error");
+        assert_eq!(String::from("match").into_safe_in_set(&set), "r#match");
+        assert_eq!("self".into_safe_in_set(&set), "self_");
+        assert_eq!("hello".into_safe_in_set(&set), "hello");
+
+        let set = set.escape_with(EscapeStrategy::TrailingUnderscore);
+        assert_eq!("Error".into_safe_in_set(&set), "Error_");
+        assert_eq!("type".into_safe_in_set(&set), "type_");
+        assert_eq!("r#type".into_safe_in_set(&set), "type_");
+        assert_eq!(String::from("r#foo").into_safe_in_set(&set), "foo");
+
+        let set = vec![String::from("inner")];
+        assert_eq!("inner".into_safe_in_set(&set), "r#inner");
+        assert_eq!("match".into_safe_in_set(&set), "r#match");
+        assert_eq!("r#match".into_safe_in_set(&set), "r#match");
+        assert_eq!("self".into_safe_in_set(&set), "self_");
+        assert_eq!("self_".into_safe_in_set(&set), "self__");
+        assert_eq!(String::from("r#foo").into_safe_in_set(&set), "foo");
+
+        let set = set.escape_with(EscapeStrategy::TrailingUnderscore);
+        assert_eq!("inner".into_safe_in_set(&set), "inner_");
+        assert_eq!("match".into_safe_in_set(&set), "match");
+        assert_eq!("self".into_safe_in_set(&set), "self");
+    }
+
+    #[test]
+    fn conflicts_in() {
+        let targets = [&RustKeywords::EDITION_2018 as &dyn KeywordSet, &["async", "def"], &["def"]];
+        assert!("async".conflicts_in(&targets).eq([0, 1]));
+        assert!(String::from("def").conflicts_in(&targets).eq([1, 2]));
+        assert!("dyn".conflicts_in(&targets).eq([0]));
+        assert_eq!("hello".conflicts_in(&targets).count(), 0);
+        assert_eq!("hello".conflicts_in(&[]).count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all() {
+        let targets = [&RustKeywords::EDITION_2018 as &dyn KeywordSet, &["def", "def_", "class"]];
+        assert_eq!("def".into_safe_in_all(&targets).unwrap(), "def_2");
+        assert_eq!(String::from("class").into_safe_in_all(&targets).unwrap(), "class_");
+        assert_eq!("self".into_safe_in_all(&targets).unwrap(), "self_");
+        assert_eq!("r#fn".into_safe_in_all(&targets).unwrap(), "fn_");
+        assert_eq!("r#name".into_safe_in_all(&targets).unwrap(), "name");
+        assert_eq!(String::from("name").into_safe_in_all(&targets).unwrap(), "name");
+        assert_eq!("def".into_safe_in_all(&[]).unwrap(), "def");
+
+        assert_eq!("foo-bar".into_safe_in_all(&targets).unwrap(), "foo_bar");
+        assert_eq!("2fast".into_safe_in_all(&targets).unwrap(), "_2fast");
+        assert_eq!("naïve".into_safe_in_all(&targets).unwrap(), "naïve");
+        assert_eq!("naïve-ish".into_safe_in_all(&targets).unwrap(), "naïve_ish");
+        assert_eq!("".into_safe_in_all(&targets).unwrap(), "__");
+        assert_eq!("🎉".into_safe_in_all(&[]).unwrap(), "_");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all_gives_up() {
+        struct Everything;
+        impl KeywordSet for Everything {
+            fn is_reserved(&self, _: &str) -> bool {
+                true
+            }
+        }
+
+        let targets = [&RustKeywords::EDITION_2018 as &dyn KeywordSet, &Everything];
+        assert_eq!("name".into_safe_in_all(&targets), Err(ConflictError { set: 1 }));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn matches_into_safe() {
+        for name in ["hello", "match", "self", "self_", "_", "r#match", "r#foo", "gen"] {
+            assert_eq!(name.into_safe_in_set(&RustKeywords::default()), name.into_safe());
+        }
+    }
+}
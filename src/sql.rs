@@ -0,0 +1,294 @@
+//! SQL reserved words and identifier quoting for a few common dialects.
+//!
+//! Reserved words are matched case-insensitively. Names are quoted if they're reserved, if they
+//! aren't plain identifiers, or if the dialect would change their case when unquoted.
+//!
+//! ```
+//! use check_keyword::sql::{quote_if_needed, Dialect};
+//!
+//! assert_eq!(quote_if_needed("user", Dialect::Postgres), "\"user\"");
+//! assert_eq!(quote_if_needed("order", Dialect::MySql), "`order`");
+//! assert_eq!(quote_if_needed("key", Dialect::MsSql), "[key]");
+//! assert_eq!(quote_if_needed("userName", Dialect::Postgres), "\"userName\"");
+//! assert_eq!(quote_if_needed("userName", Dialect::Sqlite), "userName");
+//! ```
+//!
+//! [Dialect] is also a [KeywordSet], so this is the same as [CheckKeyword::into_safe_in_set].
+
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// A SQL dialect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// Folds unquoted identifiers to lowercase, and quotes with `"`.
+    Postgres,
+    /// Case-insensitive column names, and quotes with `` ` ``.
+    MySql,
+    /// Case-insensitive, and quotes with `"`.
+    Sqlite,
+    /// Case-insensitive with the default collation, and quotes with `[]`.
+    MsSql,
+}
+
+/// PostgreSQL's reserved key words, including those that can be function or type names.
+pub const POSTGRES_RESERVED_WORDS: &[&str] = &[
+    "ALL", "ANALYSE", "ANALYZE", "AND", "ANY", "ARRAY", "AS", "ASC", "ASYMMETRIC", "AUTHORIZATION",
+    "BINARY", "BOTH", "CASE", "CAST", "CHECK", "COLLATE", "COLLATION", "COLUMN", "CONCURRENTLY",
+    "CONSTRAINT", "CREATE", "CROSS", "CURRENT_CATALOG", "CURRENT_DATE", "CURRENT_ROLE",
+    "CURRENT_SCHEMA", "CURRENT_TIME", "CURRENT_TIMESTAMP", "CURRENT_USER", "DEFAULT", "DEFERRABLE",
+    "DESC", "DISTINCT", "DO", "ELSE", "END", "EXCEPT", "FALSE", "FETCH", "FOR", "FOREIGN", "FREEZE",
+    "FROM", "FULL", "GRANT", "GROUP", "HAVING", "ILIKE", "IN", "INITIALLY", "INNER", "INTERSECT",
+    "INTO", "IS", "ISNULL", "JOIN", "LATERAL", "LEADING", "LEFT", "LIKE", "LIMIT", "LOCALTIME",
+    "LOCALTIMESTAMP", "NATURAL", "NOT", "NOTNULL", "NULL", "OFFSET", "ON", "ONLY", "OR", "ORDER",
+    "OUTER", "OVERLAPS", "PLACING", "PRIMARY", "REFERENCES", "RETURNING", "RIGHT", "SELECT",
+    "SESSION_USER", "SIMILAR", "SOME", "SYMMETRIC", "SYSTEM_USER", "TABLE", "TABLESAMPLE", "THEN",
+    "TO", "TRAILING", "TRUE", "UNION", "UNIQUE", "USER", "USING", "VARIADIC", "VERBOSE", "WHEN",
+    "WHERE", "WINDOW", "WITH",
+];
+
+/// MySQL 8's reserved words.
+pub const MYSQL_RESERVED_WORDS: &[&str] = &[
+    "ACCESSIBLE", "ADD", "ALL", "ALTER", "ANALYZE", "AND", "AS", "ASC", "ASENSITIVE", "BEFORE",
+    "BETWEEN", "BIGINT", "BINARY", "BLOB", "BOTH", "BY", "CALL", "CASCADE", "CASE", "CHANGE", "CHAR",
+    "CHARACTER", "CHECK", "COLLATE", "COLUMN", "CONDITION", "CONSTRAINT", "CONTINUE", "CONVERT",
+    "CREATE", "CROSS", "CUBE", "CUME_DIST", "CURRENT_DATE", "CURRENT_TIME", "CURRENT_TIMESTAMP",
+    "CURRENT_USER", "CURSOR", "DATABASE", "DATABASES", "DAY_HOUR", "DAY_MICROSECOND", "DAY_MINUTE",
+    "DAY_SECOND", "DEC", "DECIMAL", "DECLARE", "DEFAULT", "DELAYED", "DELETE", "DENSE_RANK", "DESC",
+    "DESCRIBE", "DETERMINISTIC", "DISTINCT", "DISTINCTROW", "DIV", "DOUBLE", "DROP", "DUAL", "EACH",
+    "ELSE", "ELSEIF", "EMPTY", "ENCLOSED", "ESCAPED", "EXCEPT", "EXISTS", "EXIT", "EXPLAIN", "FALSE",
+    "FETCH", "FIRST_VALUE", "FLOAT", "FLOAT4", "FLOAT8", "FOR", "FORCE", "FOREIGN", "FROM",
+    "FULLTEXT", "FUNCTION", "GENERATED", "GET", "GRANT", "GROUP", "GROUPING", "GROUPS", "HAVING",
+    "HIGH_PRIORITY", "HOUR_MICROSECOND", "HOUR_MINUTE", "HOUR_SECOND", "IF", "IGNORE", "IN", "INDEX",
+    "INFILE", "INNER", "INOUT", "INSENSITIVE", "INSERT", "INT", "INT1", "INT2", "INT3", "INT4",
+    "INT8", "INTEGER", "INTERSECT", "INTERVAL", "INTO", "IO_AFTER_GTIDS", "IO_BEFORE_GTIDS", "IS",
+    "ITERATE", "JOIN", "JSON_TABLE", "KEY", "KEYS", "KILL", "LAG", "LAST_VALUE", "LATERAL", "LEAD",
+    "LEADING", "LEAVE", "LEFT", "LIKE", "LIMIT", "LINEAR", "LINES", "LOAD", "LOCALTIME",
+    "LOCALTIMESTAMP", "LOCK", "LONG", "LONGBLOB", "LONGTEXT", "LOOP", "LOW_PRIORITY", "MASTER_BIND",
+    "MASTER_SSL_VERIFY_SERVER_CERT", "MATCH", "MAXVALUE", "MEDIUMBLOB", "MEDIUMINT", "MEDIUMTEXT",
+    "MIDDLEINT", "MINUTE_MICROSECOND", "MINUTE_SECOND", "MOD", "MODIFIES", "NATURAL", "NOT",
+    "NO_WRITE_TO_BINLOG", "NTH_VALUE", "NTILE", "NULL", "NUMERIC", "OF", "ON", "OPTIMIZE",
+    "OPTIMIZER_COSTS", "OPTION", "OPTIONALLY", "OR", "ORDER", "OUT", "OUTER", "OUTFILE", "OVER",
+    "PARTITION", "PERCENT_RANK", "PRECISION", "PRIMARY", "PROCEDURE", "PURGE", "RANGE", "RANK",
+    "READ", "READS", "READ_WRITE", "REAL", "RECURSIVE", "REFERENCES", "REGEXP", "RELEASE", "RENAME",
+    "REPEAT", "REPLACE", "REQUIRE", "RESIGNAL", "RESTRICT", "RETURN", "REVOKE", "RIGHT", "RLIKE",
+    "ROW", "ROWS", "ROW_NUMBER", "SCHEMA", "SCHEMAS", "SECOND_MICROSECOND", "SELECT", "SENSITIVE",
+    "SEPARATOR", "SET", "SHOW", "SIGNAL", "SMALLINT", "SPATIAL", "SPECIFIC", "SQL", "SQLEXCEPTION",
+    "SQLSTATE", "SQLWARNING", "SQL_BIG_RESULT", "SQL_CALC_FOUND_ROWS", "SQL_SMALL_RESULT", "SSL",
+    "STARTING", "STORED", "STRAIGHT_JOIN", "SYSTEM", "TABLE", "TERMINATED", "THEN", "TINYBLOB",
+    "TINYINT", "TINYTEXT", "TO", "TRAILING", "TRIGGER", "TRUE", "UNDO", "UNION", "UNIQUE", "UNLOCK",
+    "UNSIGNED", "UPDATE", "USAGE", "USE", "USING", "UTC_DATE", "UTC_TIME", "UTC_TIMESTAMP", "VALUES",
+    "VARBINARY", "VARCHAR", "VARCHARACTER", "VARYING", "VIRTUAL", "WHEN", "WHERE", "WHILE", "WINDOW",
+    "WITH", "WRITE", "XOR", "YEAR_MONTH", "ZEROFILL",
+];
+
+/// SQLite's keywords.
+pub const SQLITE_RESERVED_WORDS: &[&str] = &[
+    "ABORT", "ACTION", "ADD", "AFTER", "ALL", "ALTER", "ALWAYS", "ANALYZE", "AND", "AS", "ASC",
+    "ATTACH", "AUTOINCREMENT", "BEFORE", "BEGIN", "BETWEEN", "BY", "CASCADE", "CASE", "CAST", "CHECK",
+    "COLLATE", "COLUMN", "COMMIT", "CONFLICT", "CONSTRAINT", "CREATE", "CROSS", "CURRENT",
+    "CURRENT_DATE", "CURRENT_TIME", "CURRENT_TIMESTAMP", "DATABASE", "DEFAULT", "DEFERRABLE",
+    "DEFERRED", "DELETE", "DESC", "DETACH", "DISTINCT", "DO", "DROP", "EACH", "ELSE", "END", "ESCAPE",
+    "EXCEPT", "EXCLUDE", "EXCLUSIVE", "EXISTS", "EXPLAIN", "FAIL", "FILTER", "FIRST", "FOLLOWING",
+    "FOR", "FOREIGN", "FROM", "FULL", "GENERATED", "GLOB", "GROUP", "GROUPS", "HAVING", "IF",
+    "IGNORE", "IMMEDIATE", "IN", "INDEX", "INDEXED", "INITIALLY", "INNER", "INSERT", "INSTEAD",
+    "INTERSECT", "INTO", "IS", "ISNULL", "JOIN", "KEY", "LAST", "LEFT", "LIKE", "LIMIT", "MATCH",
+    "MATERIALIZED", "NATURAL", "NO", "NOT", "NOTHING", "NOTNULL", "NULL", "NULLS", "OF", "OFFSET",
+    "ON", "OR", "ORDER", "OTHERS", "OUTER", "OVER", "PARTITION", "PLAN", "PRAGMA", "PRECEDING",
+    "PRIMARY", "QUERY", "RAISE", "RANGE", "RECURSIVE", "REFERENCES", "REGEXP", "REINDEX", "RELEASE",
+    "RENAME", "REPLACE", "RESTRICT", "RETURNING", "RIGHT", "ROLLBACK", "ROW", "ROWS", "SAVEPOINT",
+    "SELECT", "SET", "TABLE", "TEMP", "TEMPORARY", "THEN", "TIES", "TO", "TRANSACTION", "TRIGGER",
+    "UNBOUNDED", "UNION", "UNIQUE", "UPDATE", "USING", "VACUUM", "VALUES", "VIEW", "VIRTUAL", "WHEN",
+    "WHERE", "WINDOW", "WITH", "WITHOUT",
+];
+
+/// SQL Server's reserved keywords.
+pub const MSSQL_RESERVED_WORDS: &[&str] = &[
+    "ADD", "ALL", "ALTER", "AND", "ANY", "AS", "ASC", "AUTHORIZATION", "BACKUP", "BEGIN", "BETWEEN",
+    "BREAK", "BROWSE", "BULK", "BY", "CASCADE", "CASE", "CHECK", "CHECKPOINT", "CLOSE", "CLUSTERED",
+    "COALESCE", "COLLATE", "COLUMN", "COMMIT", "COMPUTE", "CONSTRAINT", "CONTAINS", "CONTAINSTABLE",
+    "CONTINUE", "CONVERT", "CREATE", "CROSS", "CURRENT", "CURRENT_DATE", "CURRENT_TIME",
+    "CURRENT_TIMESTAMP", "CURRENT_USER", "CURSOR", "DATABASE", "DBCC", "DEALLOCATE", "DECLARE",
+    "DEFAULT", "DELETE", "DENY", "DESC", "DISK", "DISTINCT", "DISTRIBUTED", "DOUBLE", "DROP", "DUMP",
+    "ELSE", "END", "ERRLVL", "ESCAPE", "EXCEPT", "EXEC", "EXECUTE", "EXISTS", "EXIT", "EXTERNAL",
+    "FETCH", "FILE", "FILLFACTOR", "FOR", "FOREIGN", "FREETEXT", "FREETEXTTABLE", "FROM", "FULL",
+    "FUNCTION", "GOTO", "GRANT", "GROUP", "HAVING", "HOLDLOCK", "IDENTITY", "IDENTITYCOL",
+    "IDENTITY_INSERT", "IF", "IN", "INDEX", "INNER", "INSERT", "INTERSECT", "INTO", "IS", "JOIN",
+    "KEY", "KILL", "LEFT", "LIKE", "LINENO", "LOAD", "MERGE", "NATIONAL", "NOCHECK",
+    "NONCLUSTERED", "NOT", "NULL", "NULLIF", "OF", "OFF", "OFFSETS", "ON", "OPEN", "OPENDATASOURCE",
+    "OPENQUERY", "OPENROWSET", "OPENXML", "OPTION", "OR", "ORDER", "OUTER", "OVER", "PERCENT",
+    "PIVOT", "PLAN", "PRECISION", "PRIMARY", "PRINT", "PROC", "PROCEDURE", "PUBLIC", "RAISERROR",
+    "READ", "READTEXT", "RECONFIGURE", "REFERENCES", "REPLICATION", "RESTORE", "RESTRICT", "RETURN",
+    "REVERT", "REVOKE", "RIGHT", "ROLLBACK", "ROWCOUNT", "ROWGUIDCOL", "RULE", "SAVE", "SCHEMA",
+    "SECURITYAUDIT", "SELECT", "SEMANTICKEYPHRASETABLE", "SEMANTICSIMILARITYDETAILSTABLE",
+    "SEMANTICSIMILARITYTABLE", "SESSION_USER", "SET", "SETUSER", "SHUTDOWN", "SOME", "STATISTICS",
+    "SYSTEM_USER", "TABLE", "TABLESAMPLE", "TEXTSIZE", "THEN", "TO", "TOP", "TRAN", "TRANSACTION",
+    "TRIGGER", "TRUNCATE", "TRY_CONVERT", "TSEQUAL", "UNION", "UNIQUE", "UNPIVOT", "UPDATE",
+    "UPDATETEXT", "USE", "USER", "VALUES", "VARYING", "VIEW", "WAITFOR", "WHEN", "WHERE", "WHILE",
+    "WITH", "WRITETEXT",
+];
+
+impl Dialect {
+    /// The dialect's reserved words, in uppercase.
+    pub fn reserved_words(self) -> &'static [&'static str] {
+        match self {
+            Dialect::Postgres => POSTGRES_RESERVED_WORDS,
+            Dialect::MySql => MYSQL_RESERVED_WORDS,
+            Dialect::Sqlite => SQLITE_RESERVED_WORDS,
+            Dialect::MsSql => MSSQL_RESERVED_WORDS,
+        }
+    }
+
+    /// Checks if `name` is a reserved word, ignoring case.
+    pub fn is_reserved_word(self, name: &str) -> bool {
+        self.reserved_words().iter().any(|word| word.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks if unquoted identifiers are folded to lowercase, so names with uppercase
+    /// letters need to be quoted to keep them.
+    pub fn folds_to_lowercase(self) -> bool {
+        self == Dialect::Postgres
+    }
+
+    /// The characters that open and close a quoted identifier.
+    pub fn quotes(self) -> (char, char) {
+        match self {
+            Dialect::Postgres | Dialect::Sqlite => ('"', '"'),
+            Dialect::MySql => ('`', '`'),
+            Dialect::MsSql => ('[', ']'),
+        }
+    }
+
+    /// Checks if `name` has to be quoted to be used as an identifier.
+    pub fn needs_quotes(self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let plain = match chars.next() {
+            Some(c) if c == '_' || c.is_ascii_alphabetic() => {
+                chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+            }
+            _ => false
+        };
+        !plain
+            || self.is_reserved_word(name)
+            || (self.folds_to_lowercase() && name.bytes().any(|b| b.is_ascii_uppercase()))
+    }
+}
+
+/// Quotes `name`, doubling any closing quote characters in it.
+#[cfg(feature = "alloc")]
+pub fn quote(name: &str, dialect: Dialect) -> String {
+    let (open, close) = dialect.quotes();
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push(open);
+    for c in name.chars() {
+        if c == close {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push(close);
+    quoted
+}
+
+/// Quotes `name` only if it needs to be, like [CheckKeyword::into_safe] for SQL.
+#[cfg(feature = "alloc")]
+pub fn quote_if_needed(name: &str, dialect: Dialect) -> Cow<'_, str> {
+    if dialect.needs_quotes(name) {
+        Cow::Owned(quote(name, dialect))
+    } else {
+        Cow::Borrowed(name)
+    }
+}
+
+impl KeywordSet for Dialect {
+    fn is_reserved(&self, name: &str) -> bool {
+        self.needs_quotes(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Custom(match self {
+            Dialect::Postgres => &|name| quote(name, Dialect::Postgres),
+            Dialect::MySql => &|name| quote(name, Dialect::MySql),
+            Dialect::Sqlite => &|name| quote(name, Dialect::Sqlite),
+            Dialect::MsSql => &|name| quote(name, Dialect::MsSql),
+        })
+    }
+
+    /// Keeps only ASCII letters, digits, and `_`, and lowercases the name if the dialect folds it.
+    #[cfg(feature = "alloc")]
+    fn normalize<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let mut normalized: String = name.chars()
+            .filter(|&c| c == '_' || c.is_ascii_alphanumeric())
+            .map(|c| if self.folds_to_lowercase() { c.to_ascii_lowercase() } else { c })
+            .collect();
+        if !normalized.starts_with(|c: char| c == '_' || c.is_ascii_alphabetic()) {
+            normalized.insert(0, '_');
+        }
+        if normalized == name {
+            Cow::Borrowed(name)
+        } else {
+            Cow::Owned(normalized)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_words() {
+        assert!(Dialect::Postgres.is_reserved_word("user"));
+        assert!(Dialect::Postgres.is_reserved_word("USER"));
+        assert!(!Dialect::Sqlite.is_reserved_word("user"));
+        assert!(Dialect::MySql.is_reserved_word("Order"));
+        assert!(Dialect::MsSql.is_reserved_word("key"));
+        assert!(!Dialect::Postgres.is_reserved_word("key"));
+    }
+
+    #[test]
+    fn needs_quotes() {
+        assert!(!Dialect::Postgres.needs_quotes("user_name"));
+        assert!(Dialect::Postgres.needs_quotes("userName"));
+        assert!(!Dialect::MySql.needs_quotes("userName"));
+        assert!(Dialect::Sqlite.needs_quotes("user name"));
+        assert!(Dialect::MsSql.needs_quotes("1st"));
+        assert!(Dialect::MsSql.needs_quotes(""));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn quote_if_needed() {
+        assert_eq!(super::quote_if_needed("user", Dialect::Postgres), "\"user\"");
+        assert_eq!(super::quote_if_needed("order", Dialect::MySql), "`order`");
+        assert_eq!(super::quote_if_needed("order", Dialect::Sqlite), "\"order\"");
+        assert_eq!(super::quote_if_needed("key", Dialect::MsSql), "[key]");
+        assert_eq!(super::quote_if_needed("id", Dialect::MsSql), "id");
+
+        assert_eq!(super::quote_if_needed("a\"b", Dialect::Postgres), "\"a\"\"b\"");
+        assert_eq!(super::quote_if_needed("a]b", Dialect::MsSql), "[a]]b]");
+        assert_eq!(super::quote_if_needed("a`b", Dialect::MySql), "`a``b`");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("user".into_safe_in_set(&Dialect::Postgres), "\"user\"");
+        assert_eq!(String::from("select").into_safe_in_set(&Dialect::MsSql), "[select]");
+        assert_eq!("name".into_safe_in_set(&Dialect::MySql), "name");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_all() {
+        let targets = [&RustKeywords::EDITION_2021 as &dyn KeywordSet, &Dialect::Postgres];
+        assert_eq!("user".into_safe_in_all(&targets).unwrap(), "user_");
+        assert_eq!("user name".into_safe_in_all(&targets).unwrap(), "user_name");
+        assert_eq!("userName".into_safe_in_all(&targets).unwrap(), "username");
+        assert_eq!("Order".into_safe_in_all(&targets).unwrap(), "order_");
+        assert_eq!("naïve".into_safe_in_all(&targets).unwrap(), "nave");
+    }
+}
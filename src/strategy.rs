@@ -0,0 +1,119 @@
+use super::*;
+
+use alloc::{format, string::String};
+use core::fmt;
+
+/// How to escape a keyword, for [CheckKeyword::into_safe_with].
+#[derive(Copy, Clone, Default)]
+pub enum EscapeStrategy<'a> {
+    /// `r#type`, the same as [CheckKeyword::into_safe].
+    ///
+    /// Keywords that can't be raw identifiers get a trailing underscore instead.
+    #[default]
+    RawPrefix,
+    /// `type_`
+    TrailingUnderscore,
+    /// `_type`
+    LeadingUnderscore,
+    /// Adds a prefix and a suffix, like `kw_type` with `Affix { prefix: "kw_", suffix: "" }`.
+    /// They can be borrowed from anywhere, like a generator's config.
+    Affix {
+        prefix: &'a str,
+        suffix: &'a str,
+    },
+    /// Calls a function or closure with the keyword.
+    ///
+    /// ```
+    /// use check_keyword::{CheckKeyword, EscapeStrategy};
+    ///
+    /// let prefix = String::from("kw_");
+    /// let escape = |name: &str| format!("{}{}", prefix, name);
+    ///
+    /// assert_eq!("type".into_safe_with(EscapeStrategy::Custom(&escape)), "kw_type");
+    /// ```
+    Custom(&'a dyn Fn(&str) -> String),
+}
+
+impl fmt::Debug for EscapeStrategy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EscapeStrategy::RawPrefix => write!(f, "RawPrefix"),
+            EscapeStrategy::TrailingUnderscore => write!(f, "TrailingUnderscore"),
+            EscapeStrategy::LeadingUnderscore => write!(f, "LeadingUnderscore"),
+            EscapeStrategy::Affix { prefix, suffix } => {
+                f.debug_struct("Affix").field("prefix", prefix).field("suffix", suffix).finish()
+            }
+            EscapeStrategy::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl EscapeStrategy<'_> {
+    /// Escapes `name`, without checking whether it's a keyword first.
+    ///
+    /// ```
+    /// use check_keyword::EscapeStrategy;
+    ///
+    /// assert_eq!(EscapeStrategy::LeadingUnderscore.apply("type"), "_type");
+    /// ```
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            EscapeStrategy::RawPrefix if NON_RAW_KEYWORDS.contains(&name) => format!("{}_", name),
+            EscapeStrategy::RawPrefix => format!("r#{}", name),
+            EscapeStrategy::TrailingUnderscore => format!("{}_", name),
+            EscapeStrategy::LeadingUnderscore => format!("_{}", name),
+            EscapeStrategy::Affix { prefix, suffix } => format!("{}{}{}", prefix, name, suffix),
+            EscapeStrategy::Custom(escape) => escape(name),
+        }
+    }
+}
+
+/// Escapes `name` with `strategy` if it's a keyword in `edition`.
+pub(crate) fn into_safe_with<'a>(name: &'a str, strategy: EscapeStrategy<'_>, edition: Edition) -> Cow<'a, str> {
+    match strategy {
+        EscapeStrategy::RawPrefix => into_safe_cow_for(name, edition),
+        _ if is_keyword_in(unraw(name), edition) => Cow::Owned(strategy.apply(unraw(name))),
+        _ => Cow::Borrowed(unraw(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_safe_with() {
+        assert_eq!("type".into_safe_with(EscapeStrategy::RawPrefix), "r#type");
+        assert_eq!("type".into_safe_with(EscapeStrategy::TrailingUnderscore), "type_");
+        assert_eq!(String::from("type").into_safe_with(EscapeStrategy::LeadingUnderscore), "_type");
+        assert_eq!("type".into_safe_with(EscapeStrategy::Affix { prefix: "kw_", suffix: "" }), "kw_type");
+        assert_eq!("type".into_safe_with(EscapeStrategy::Custom(&|name| name.to_uppercase())), "TYPE");
+
+        let prefix = String::from("kw_");
+        let escape = |name: &str| format!("{}{}", prefix, name);
+        assert_eq!("fn".into_safe_with(EscapeStrategy::Custom(&escape)), "kw_fn");
+
+        let suffix = String::from("_kw");
+        assert_eq!("fn".into_safe_with(EscapeStrategy::Affix { prefix: "", suffix: &suffix }), "fn_kw");
+
+        assert_eq!("hello".into_safe_with(EscapeStrategy::LeadingUnderscore), "hello");
+        assert_eq!("r#type".into_safe_with(EscapeStrategy::TrailingUnderscore), "type_");
+        assert_eq!("r#foo".into_safe_with(EscapeStrategy::TrailingUnderscore), "foo");
+    }
+
+    #[test]
+    fn into_safe_with_for() {
+        let strategy = EscapeStrategy::TrailingUnderscore;
+        assert_eq!("gen".into_safe_with_for(strategy, Edition::Edition2021), "gen");
+        assert_eq!("gen".into_safe_with_for(strategy, Edition::Edition2024), "gen_");
+        assert_eq!(String::from("async").into_safe_with_for(strategy, Edition::Edition2015), "async");
+        assert_eq!("gen".into_safe_with_for(EscapeStrategy::RawPrefix, Edition::Edition2024), "r#gen");
+    }
+
+    #[test]
+    fn raw_prefix_matches_into_safe() {
+        for name in ["hello", "match", "self", "self_", "_", "r#match", "r#foo"] {
+            assert_eq!(name.into_safe_with(EscapeStrategy::RawPrefix), name.into_safe());
+        }
+    }
+}
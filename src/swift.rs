@@ -0,0 +1,63 @@
+//! Swift keywords, which can all be used as names when quoted with backticks.
+//!
+//! ```
+//! use check_keyword::CheckKeyword;
+//! use check_keyword::swift::Swift;
+//!
+//! assert!("protocol".is_keyword_in_set(&Swift));
+//! assert_eq!("protocol".into_safe_in_set(&Swift), "`protocol`");
+//! ```
+
+use super::*;
+
+/// Swift's keywords used in declarations, statements, expressions, and types.
+///
+/// Contextual keywords like `mutating` and `get` can be used as names, so they aren't included.
+pub const KEYWORDS: &[&str] = &[
+    "Any", "Self", "as", "associatedtype", "await", "break", "case", "catch", "class", "continue",
+    "default", "defer", "deinit", "do", "else", "enum", "extension", "fallthrough", "false",
+    "fileprivate", "for", "func", "guard", "if", "import", "in", "init", "inout", "internal", "is",
+    "let", "nil", "open", "operator", "precedencegroup", "private", "protocol", "public", "repeat",
+    "rethrows", "return", "self", "static", "struct", "subscript", "super", "switch", "throw",
+    "throws", "true", "try", "typealias", "var", "where", "while",
+];
+
+/// Checks if `name` is a keyword.
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// A [KeywordSet] of Swift keywords, escaped with backticks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Swift;
+
+impl KeywordSet for Swift {
+    fn is_reserved(&self, name: &str) -> bool {
+        is_keyword(name)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn escape_strategy(&self) -> EscapeStrategy<'_> {
+        EscapeStrategy::Affix { prefix: "`", suffix: "`" }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords() {
+        assert!(is_keyword("guard"));
+        assert!(is_keyword("Self"));
+        assert!(!is_keyword("mutating"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn into_safe_in_set() {
+        assert_eq!("default".into_safe_in_set(&Swift), "`default`");
+        assert_eq!(String::from("func").into_safe_in_set(&Swift), "`func`");
+        assert_eq!("value".into_safe_in_set(&Swift), "value");
+    }
+}
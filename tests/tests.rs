@@ -1,6 +1,61 @@
-use check_keyword::CheckKeyword;
+#![cfg(feature = "alloc")]
+
+use check_keyword::{CheckKeyword, Edition, Keyword, SanitizeOptions};
+use proptest::prelude::*;
 
 #[test]
 fn interface() {
     assert_eq!("match".into_safe(), "r#match");
-}
\ No newline at end of file
+}
+
+const EDITIONS: [Edition; 4] = [
+    Edition::Edition2015,
+    Edition::Edition2018,
+    Edition::Edition2021,
+    Edition::Edition2024
+];
+
+/// Keywords with some underscores and letters around them, plus arbitrary strings.
+fn names() -> impl Strategy<Value = String> {
+    let keyword = prop::sample::select(Keyword::ALL).prop_map(Keyword::as_str);
+    prop_oneof![
+        (keyword, "_{0,3}").prop_map(|(keyword, underscores)| format!("{}{}", keyword, underscores)),
+        "_{0,4}[a-z]{0,2}",
+        "\\PC*"
+    ].prop_filter("already raw", |name| !name.starts_with("r#"))
+}
+
+proptest! {
+    #[test]
+    fn original_name_round_trip(name in names()) {
+        for edition in EDITIONS {
+            let safe = name.as_str().into_safe_for(edition);
+            prop_assert_eq!(safe.original_name(), name.as_str());
+        }
+    }
+
+    #[test]
+    fn into_ident_is_valid(name in names()) {
+        prop_assert!(name.as_str().into_ident().is_valid_ident());
+    }
+
+    #[test]
+    fn into_ident_with_is_valid(
+        name in names(),
+        replacement in any::<char>(),
+        digit_prefix in prop_oneof![prop::sample::select(vec!["_", "n", "1", "-", "", "a-", "r#"]).prop_map(String::from), any::<String>()],
+        edition in prop::sample::select(&EDITIONS[..]),
+    ) {
+        let options = SanitizeOptions { replacement, digit_prefix: &digit_prefix, edition };
+        prop_assert!(name.as_str().into_ident_with(&options).is_valid_ident_in(edition));
+    }
+
+    #[test]
+    fn encode_ident_round_trip(name in prop_oneof![names(), any::<String>()]) {
+        let encoded = name.as_str().encode_ident();
+        for edition in EDITIONS {
+            prop_assert!(encoded.is_valid_ident_in(edition));
+        }
+        prop_assert_eq!(encoded.decode_ident(), Ok(name));
+    }
+}